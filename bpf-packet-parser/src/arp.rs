@@ -0,0 +1,396 @@
+mod error;
+mod header_values;
+
+pub use error::*;
+pub use header_values::*;
+
+use crate::buffer::PacketBuffer;
+use crate::cursor::PacketCursor;
+use crate::ethernet::{EtherType, MacAddr};
+#[cfg(feature = "fmt")]
+use core::fmt::Debug;
+
+// All ranges are [start..end).
+// This means start is included, end is not included.
+pub static HARDWARE_TYPE_START: usize = 0;
+pub static HARDWARE_TYPE_END: usize = 2;
+pub static PROTOCOL_TYPE_START: usize = 2;
+pub static PROTOCOL_TYPE_END: usize = 4;
+pub static HARDWARE_LEN: usize = 4;
+pub static PROTOCOL_LEN: usize = 5;
+pub static OPERATION_START: usize = 6;
+pub static OPERATION_END: usize = 8;
+pub static ADDRESSES_START: usize = 8;
+// Address offsets of Ethernet/IPv4 packets.
+pub static SENDER_MAC_START: usize = 8;
+pub static SENDER_MAC_END: usize = 14;
+pub static SENDER_IPV4_START: usize = 14;
+pub static SENDER_IPV4_END: usize = 18;
+pub static TARGET_MAC_START: usize = 18;
+pub static TARGET_MAC_END: usize = 24;
+pub static TARGET_IPV4_START: usize = 24;
+pub static TARGET_IPV4_END: usize = 28;
+
+pub static ARP_FIXED_HEADER_SIZE: usize = 8;
+pub static ARP_ETHERNET_IPV4_SIZE: usize = 28;
+pub static HARDWARE_TYPE_ETHERNET: u16 = 1;
+
+/// ARP packet with any hardware and protocol address lengths, found in the payload of frames
+/// with [`EtherType::Arp`] and [`EtherType::Rarp`]. See [`EthernetIpv4Arp`] for the common
+/// Ethernet/IPv4 case.
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(PartialEq, Eq)]
+pub struct ArpPacket<B> {
+    // size of 8B + 2 * (hardware len + protocol len)
+    header: B,
+}
+
+impl<B: PacketBuffer> ArpPacket<B> {
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn new_bpf(buf: B, end: usize) -> Result<(Self, B), ArpBpfCreationError> {
+        let mut cursor = PacketCursor::new(buf, end);
+        let header = Self::from_cursor(&mut cursor)?;
+        Ok((header, cursor.into_inner()))
+    }
+
+    /// Takes the packet including addresses from the front of `cursor` and leaves the cursor
+    /// behind the target protocol address.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_cursor(cursor: &mut PacketCursor<B>) -> Result<Self, ArpBpfCreationError> {
+        // We read at least the first 8 bytes of the packet:
+        // 2 bytes hardware type
+        // 2 bytes protocol type
+        // 1 byte hardware address length
+        // 1 byte protocol address length
+        // 2 bytes operation
+        // ------------------
+        // 8 bytes total
+        // The sender and target addresses take 2 * (hardware len + protocol len) bytes.
+
+        let fixed = cursor.peek::<8>()?;
+        let hardware_len = *fixed
+            .get(HARDWARE_LEN)
+            .ok_or(ArpBpfCreationError::OutOfBoundsBufferAccess)?;
+        let protocol_len = *fixed
+            .get(PROTOCOL_LEN)
+            .ok_or(ArpBpfCreationError::OutOfBoundsBufferAccess)?;
+
+        let header = cursor.split_header_len(
+            ARP_FIXED_HEADER_SIZE + 2 * (hardware_len as usize + protocol_len as usize),
+        )?;
+
+        Ok(ArpPacket { header })
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_hardware_type(&self) -> Result<u16, ArpGetHeaderError> {
+        get_u16(&self.header, HARDWARE_TYPE_START, HARDWARE_TYPE_END)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_protocol_type(&self) -> Result<u16, ArpGetHeaderError> {
+        get_u16(&self.header, PROTOCOL_TYPE_START, PROTOCOL_TYPE_END)
+    }
+
+    /// The protocol type as [`EtherType`], unknown values are returned as
+    /// [`EtherType::EtherTypeErrorVariant`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_typed_protocol_type(&self) -> Result<EtherType, ArpGetHeaderError> {
+        Ok(EtherType::lookup_lenient(self.get_protocol_type()?))
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_hardware_len(&self) -> Result<u8, ArpGetHeaderError> {
+        self.header
+            .get(HARDWARE_LEN)
+            .copied()
+            .ok_or(ArpGetHeaderError::OutOfBoundsBufferAccess)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_protocol_len(&self) -> Result<u8, ArpGetHeaderError> {
+        self.header
+            .get(PROTOCOL_LEN)
+            .copied()
+            .ok_or(ArpGetHeaderError::OutOfBoundsBufferAccess)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_operation(&self) -> Result<u16, ArpGetHeaderError> {
+        get_u16(&self.header, OPERATION_START, OPERATION_END)
+    }
+
+    /// The operation as [`ArpOperation`], unassigned values are returned as
+    /// [`ArpOperation::ArpOperationErrorVariant`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_typed_operation(&self) -> Result<ArpOperation, ArpGetHeaderError> {
+        Ok(ArpOperation::lookup_lenient(self.get_operation()?))
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_sender_hardware_address(&self) -> Result<&[u8], ArpGetHeaderError> {
+        let start = ADDRESSES_START;
+        self.get_address(start, self.get_hardware_len()?)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_sender_protocol_address(&self) -> Result<&[u8], ArpGetHeaderError> {
+        let start = ADDRESSES_START + self.get_hardware_len()? as usize;
+        self.get_address(start, self.get_protocol_len()?)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_target_hardware_address(&self) -> Result<&[u8], ArpGetHeaderError> {
+        let start =
+            ADDRESSES_START + self.get_hardware_len()? as usize + self.get_protocol_len()? as usize;
+        self.get_address(start, self.get_hardware_len()?)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_target_protocol_address(&self) -> Result<&[u8], ArpGetHeaderError> {
+        let start = ADDRESSES_START
+            + 2 * self.get_hardware_len()? as usize
+            + self.get_protocol_len()? as usize;
+        self.get_address(start, self.get_protocol_len()?)
+    }
+
+    /// Hardware type Ethernet with 6 byte addresses and protocol type IPv4 with 4 byte
+    /// addresses, see [`EthernetIpv4Arp`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn is_ethernet_ipv4(&self) -> Result<bool, ArpGetHeaderError> {
+        Ok(self.get_hardware_type()? == HARDWARE_TYPE_ETHERNET
+            && self.get_protocol_type()? == EtherType::IpV4 as u16
+            && self.get_hardware_len()? == 6
+            && self.get_protocol_len()? == 4)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn get_address(&self, start: usize, len: u8) -> Result<&[u8], ArpGetHeaderError> {
+        self.header
+            .get(start..start + len as usize)
+            .ok_or(ArpGetHeaderError::OutOfBoundsBufferAccess)
+    }
+}
+
+impl ArpPacket<&mut [u8]> {
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn set_operation(&mut self, operation: ArpOperation) -> Result<(), ArpSetHeaderError> {
+        set_operation(self.header, operation)
+    }
+}
+
+/// ARP packet with Ethernet hardware addresses and IPv4 protocol addresses. All fields are at
+/// fixed offsets, which keeps the bounds checks simple for the BPF verifier.
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(PartialEq, Eq)]
+pub struct EthernetIpv4Arp<B> {
+    // size of 28B
+    header: B,
+}
+
+impl<B: PacketBuffer> EthernetIpv4Arp<B> {
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn new_bpf(buf: B, end: usize) -> Result<(Self, B), ArpBpfCreationError> {
+        let mut cursor = PacketCursor::new(buf, end);
+        let header = Self::from_cursor(&mut cursor)?;
+        Ok((header, cursor.into_inner()))
+    }
+
+    /// Like [`ArpPacket::from_cursor`], but fails with
+    /// [`ArpBpfCreationError::NotEthernetIpv4`] for other hardware or protocol types.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_cursor(cursor: &mut PacketCursor<B>) -> Result<Self, ArpBpfCreationError> {
+        // We read the first 28 bytes of the packet:
+        // 8 bytes fixed header
+        // 6 bytes sender MAC
+        // 4 bytes sender IPv4
+        // 6 bytes target MAC
+        // 4 bytes target IPv4
+        // ------------------
+        // 28 bytes total
+
+        let fixed = cursor.peek::<8>()?;
+        let hardware_type = u16::from_be_bytes(
+            fixed
+                .get(HARDWARE_TYPE_START..HARDWARE_TYPE_END)
+                .ok_or(ArpBpfCreationError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        );
+        let protocol_type = u16::from_be_bytes(
+            fixed
+                .get(PROTOCOL_TYPE_START..PROTOCOL_TYPE_END)
+                .ok_or(ArpBpfCreationError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        );
+        let hardware_len = *fixed
+            .get(HARDWARE_LEN)
+            .ok_or(ArpBpfCreationError::OutOfBoundsBufferAccess)?;
+        let protocol_len = *fixed
+            .get(PROTOCOL_LEN)
+            .ok_or(ArpBpfCreationError::OutOfBoundsBufferAccess)?;
+
+        if hardware_type != HARDWARE_TYPE_ETHERNET
+            || protocol_type != EtherType::IpV4 as u16
+            || hardware_len != 6
+            || protocol_len != 4
+        {
+            return Err(ArpBpfCreationError::NotEthernetIpv4 {
+                hardware_type,
+                protocol_type,
+            });
+        }
+
+        let header = cursor.split_header::<28>()?;
+
+        Ok(EthernetIpv4Arp { header })
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_operation(&self) -> Result<u16, ArpGetHeaderError> {
+        get_u16(&self.header, OPERATION_START, OPERATION_END)
+    }
+
+    /// The operation as [`ArpOperation`], unassigned values are returned as
+    /// [`ArpOperation::ArpOperationErrorVariant`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_typed_operation(&self) -> Result<ArpOperation, ArpGetHeaderError> {
+        Ok(ArpOperation::lookup_lenient(self.get_operation()?))
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_sender_mac(&self) -> Result<MacAddr<'_>, ArpGetHeaderError> {
+        Ok(MacAddr::new(
+            self.header
+                .get(SENDER_MAC_START..SENDER_MAC_END)
+                .ok_or(ArpGetHeaderError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        ))
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_sender_ipv4(&self) -> Result<&[u8; 4], ArpGetHeaderError> {
+        Ok(self
+            .header
+            .get(SENDER_IPV4_START..SENDER_IPV4_END)
+            .ok_or(ArpGetHeaderError::OutOfBoundsBufferAccess)?
+            .try_into()?)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_target_mac(&self) -> Result<MacAddr<'_>, ArpGetHeaderError> {
+        Ok(MacAddr::new(
+            self.header
+                .get(TARGET_MAC_START..TARGET_MAC_END)
+                .ok_or(ArpGetHeaderError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        ))
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_target_ipv4(&self) -> Result<&[u8; 4], ArpGetHeaderError> {
+        Ok(self
+            .header
+            .get(TARGET_IPV4_START..TARGET_IPV4_END)
+            .ok_or(ArpGetHeaderError::OutOfBoundsBufferAccess)?
+            .try_into()?)
+    }
+
+    /// Sender and target IPv4 address are the same, used by gratuitous ARP announcements.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn is_gratuitous(&self) -> Result<bool, ArpGetHeaderError> {
+        Ok(self.get_sender_ipv4()? == self.get_target_ipv4()?)
+    }
+}
+
+impl EthernetIpv4Arp<&mut [u8]> {
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn set_operation(&mut self, operation: ArpOperation) -> Result<(), ArpSetHeaderError> {
+        set_operation(self.header, operation)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn set_sender_mac(&mut self, mac: &[u8; 6]) -> Result<(), ArpSetHeaderError> {
+        self.set_bytes(SENDER_MAC_START, SENDER_MAC_END, mac)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn set_sender_ipv4(&mut self, ipv4: &[u8; 4]) -> Result<(), ArpSetHeaderError> {
+        self.set_bytes(SENDER_IPV4_START, SENDER_IPV4_END, ipv4)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn set_target_mac(&mut self, mac: &[u8; 6]) -> Result<(), ArpSetHeaderError> {
+        self.set_bytes(TARGET_MAC_START, TARGET_MAC_END, mac)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn set_target_ipv4(&mut self, ipv4: &[u8; 4]) -> Result<(), ArpSetHeaderError> {
+        self.set_bytes(TARGET_IPV4_START, TARGET_IPV4_END, ipv4)
+    }
+
+    /// Turns a request into the reply announcing `mac` for the requested target address.
+    /// The sender of the request becomes the target of the reply.
+    ///
+    /// The Ethernet addresses of the frame are not touched, see
+    /// [`EtherFrame::swap_macs`](crate::ethernet::EtherFrame::swap_macs) and
+    /// [`EtherFrame::set_source`](crate::ethernet::EtherFrame::set_source).
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn make_reply(&mut self, mac: &[u8; 6]) -> Result<(), ArpSetHeaderError> {
+        let operation = get_u16(self.header, OPERATION_START, OPERATION_END)?;
+        if operation != ArpOperation::Request as u16 {
+            return Err(ArpSetHeaderError::NotARequest { operation });
+        }
+
+        let sender: [u8; 10] = self
+            .header
+            .get(SENDER_MAC_START..SENDER_IPV4_END)
+            .ok_or(ArpSetHeaderError::OutOfBoundsBufferAccess)?
+            .try_into()?;
+        let target_ipv4: [u8; 4] = self
+            .header
+            .get(TARGET_IPV4_START..TARGET_IPV4_END)
+            .ok_or(ArpSetHeaderError::OutOfBoundsBufferAccess)?
+            .try_into()?;
+
+        self.set_bytes(TARGET_MAC_START, TARGET_IPV4_END, &sender)?;
+        self.set_sender_mac(mac)?;
+        self.set_sender_ipv4(&target_ipv4)?;
+        self.set_operation(ArpOperation::Reply)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn set_bytes(
+        &mut self,
+        start: usize,
+        end: usize,
+        value: &[u8],
+    ) -> Result<(), ArpSetHeaderError> {
+        self.header
+            .get_mut(start..end)
+            .ok_or(ArpSetHeaderError::OutOfBoundsBufferAccess)?
+            .copy_from_slice(value);
+        Ok(())
+    }
+}
+
+#[cfg_attr(feature = "inline", inline(always))]
+fn get_u16(header: &[u8], start: usize, end: usize) -> Result<u16, ArpGetHeaderError> {
+    Ok(u16::from_be_bytes(
+        header
+            .get(start..end)
+            .ok_or(ArpGetHeaderError::OutOfBoundsBufferAccess)?
+            .try_into()?,
+    ))
+}
+
+#[cfg_attr(feature = "inline", inline(always))]
+fn set_operation(header: &mut [u8], operation: ArpOperation) -> Result<(), ArpSetHeaderError> {
+    if operation == ArpOperation::ArpOperationErrorVariant {
+        return Err(ArpSetHeaderError::InvalidOperation);
+    }
+    header
+        .get_mut(OPERATION_START..OPERATION_END)
+        .ok_or(ArpSetHeaderError::OutOfBoundsBufferAccess)?
+        .copy_from_slice(&(operation as u16).to_be_bytes());
+    Ok(())
+}
@@ -0,0 +1,198 @@
+use crate::cursor::CursorError;
+use core::array::TryFromSliceError;
+#[cfg(feature = "fmt")]
+use core::fmt::{Debug, Display, Formatter};
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum ArpError {
+    BpfCreation(ArpBpfCreationError),
+    GetHeader(ArpGetHeaderError),
+    SetHeader(ArpSetHeaderError),
+}
+
+#[cfg(feature = "fmt")]
+impl Display for ArpError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ArpError::BpfCreation(err) => {
+                write!(f, "{err}")
+            }
+            ArpError::GetHeader(err) => {
+                write!(f, "{err}")
+            }
+            ArpError::SetHeader(err) => {
+                write!(f, "{err}")
+            }
+        }
+    }
+}
+
+impl From<ArpBpfCreationError> for ArpError {
+    fn from(value: ArpBpfCreationError) -> Self {
+        Self::BpfCreation(value)
+    }
+}
+
+impl From<ArpGetHeaderError> for ArpError {
+    fn from(value: ArpGetHeaderError) -> Self {
+        Self::GetHeader(value)
+    }
+}
+
+impl From<ArpSetHeaderError> for ArpError {
+    fn from(value: ArpSetHeaderError) -> Self {
+        Self::SetHeader(value)
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for ArpError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum ArpBpfCreationError {
+    NotEthernetIpv4 {
+        hardware_type: u16,
+        protocol_type: u16,
+    },
+    PacketTooShort {
+        size: usize,
+        expected: usize,
+    },
+    BpfOutOfBounds,
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for ArpBpfCreationError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+impl From<CursorError> for ArpBpfCreationError {
+    fn from(value: CursorError) -> Self {
+        match value {
+            CursorError::TooShort { size, expected } => Self::PacketTooShort { size, expected },
+            CursorError::BpfOutOfBounds => Self::BpfOutOfBounds,
+            CursorError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            CursorError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for ArpBpfCreationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NotEthernetIpv4 {
+                hardware_type,
+                protocol_type,
+            } => {
+                write!(
+                    f,
+                    "ARP packet expected to be Ethernet/IPv4, hardware type was: {hardware_type}, protocol type was: {protocol_type:#06x}"
+                )
+            }
+            Self::PacketTooShort { size, expected } => {
+                write!(
+                    f,
+                    "ARP packet expected to be at least {expected} bytes, was: {size}"
+                )
+            }
+            Self::BpfOutOfBounds => {
+                write!(f, "Bpf out of bounds")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for ArpBpfCreationError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum ArpGetHeaderError {
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for ArpGetHeaderError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for ArpGetHeaderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for ArpGetHeaderError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum ArpSetHeaderError {
+    InvalidOperation,
+    NotARequest { operation: u16 },
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for ArpSetHeaderError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+impl From<ArpGetHeaderError> for ArpSetHeaderError {
+    fn from(value: ArpGetHeaderError) -> Self {
+        match value {
+            ArpGetHeaderError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            ArpGetHeaderError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for ArpSetHeaderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidOperation => {
+                write!(f, "Can't set the ARP operation to the error variant")
+            }
+            Self::NotARequest { operation } => {
+                write!(
+                    f,
+                    "ARP operation expected to be a request, was: {operation}"
+                )
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for ArpSetHeaderError {}
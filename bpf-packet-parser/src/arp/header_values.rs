@@ -0,0 +1,40 @@
+crate::macros::generate_matching_enum_impl! {
+    /// Sources:
+    /// `<https://www.iana.org/assignments/arp-parameters/arp-parameters.xhtml>`
+    #[repr(u16)]
+    #[cfg_attr(feature = "fmt", derive(Debug))]
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub enum ArpOperation {
+        Request = 1,
+        Reply = 2,
+        /// Reverse ARP request
+        RarpRequest = 3,
+        /// Reverse ARP reply
+        RarpReply = 4,
+        DrarpRequest = 5,
+        DrarpReply = 6,
+        DrarpError = 7,
+        /// Inverse ARP request
+        InArpRequest = 8,
+        /// Inverse ARP reply
+        InArpReply = 9,
+        ArpNak = 10,
+        MarsRequest = 11,
+        MarsMulti = 12,
+        MarsMServ = 13,
+        MarsJoin = 14,
+        MarsLeave = 15,
+        MarsNak = 16,
+        MarsUnserv = 17,
+        MarsSJoin = 18,
+        MarsSLeave = 19,
+        MarsGrouplistRequest = 20,
+        MarsGrouplistReply = 21,
+        MarsRedirectMap = 22,
+        MaposUnarp = 23,
+        /// RFC 5494 experimental operation codes
+        Experimental = 24..=25,
+    }
+    (error_value: 0x0000),
+    (lookup_table: false)
+}
@@ -0,0 +1,26 @@
+use core::ops::Deref;
+
+/// Byte buffer headers are parsed from.
+///
+/// Implemented for `&[u8]` to inspect packets and for `&mut [u8]` to also modify them, e.g.
+/// `EtherFrame<&[u8]>` only offers accessors while `EtherFrame<&mut [u8]>` also offers setters.
+pub trait PacketBuffer: Sized + Default + Deref<Target = [u8]> {
+    /// Splits the buffer into `[0..mid)` and `[mid..len)`.
+    ///
+    /// Panics if `mid > len`, callers have to check the length first.
+    fn split_buffer(self, mid: usize) -> (Self, Self);
+}
+
+impl PacketBuffer for &[u8] {
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn split_buffer(self, mid: usize) -> (Self, Self) {
+        self.split_at(mid)
+    }
+}
+
+impl PacketBuffer for &mut [u8] {
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn split_buffer(self, mid: usize) -> (Self, Self) {
+        self.split_at_mut(mid)
+    }
+}
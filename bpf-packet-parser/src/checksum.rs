@@ -0,0 +1,170 @@
+//! Internet checksum (RFC 1071) helpers for the layers with a ones' complement checksum.
+
+/// Updates a checksum after a 16 bit word of the checksummed data changed from `old` to `new`,
+/// see RFC 1624.
+#[cfg_attr(feature = "inline", inline(always))]
+pub fn update_u16(checksum: u16, old: u16, new: u16) -> u16 {
+    let sum = (!checksum) as u32 + (!old) as u32 + new as u32;
+    !fold(sum)
+}
+
+/// Folds the carries of a 32 bit sum into the lower 16 bits.
+#[cfg_attr(feature = "inline", inline(always))]
+pub fn fold(sum: u32) -> u16 {
+    let sum = (sum & 0xFFFF) + (sum >> 16);
+    ((sum & 0xFFFF) + (sum >> 16)) as u16
+}
+
+/// Bytes summed by one [`sum_chunk`] call.
+pub const CHECKSUM_CHUNK_SIZE: usize = 64;
+/// Maximum number of chunks [`sum_bytes`] sums, enough for every IP payload length, see
+/// [bounded loops](crate#bounded-loops).
+pub const MAX_CHECKSUM_CHUNKS: usize = 1024;
+
+/// Adds the 16 bit words of at most [`CHECKSUM_CHUNK_SIZE`] bytes of `data` to `sum` and
+/// returns the folded sum. An odd last byte is padded with a zero byte.
+///
+/// Programs running into the instruction limit of the verifier with [`sum_bytes`] can call
+/// this for one chunk at a time, e.g. from a `bpf_loop` callback. Chunk boundaries have to be
+/// at even offsets.
+#[cfg_attr(feature = "inline", inline(always))]
+pub fn sum_chunk(sum: u32, data: &[u8]) -> u32 {
+    let mut sum = sum;
+    for i in 0..CHECKSUM_CHUNK_SIZE / 2 {
+        match data.get(i * 2..i * 2 + 2) {
+            Some(&[high, low]) => sum += u16::from_be_bytes([high, low]) as u32,
+            _ => {
+                if let Some(&high) = data.get(i * 2) {
+                    sum += u16::from_be_bytes([high, 0]) as u32;
+                }
+                break;
+            }
+        }
+    }
+    fold(sum) as u32
+}
+
+/// Adds the 16 bit words of `data` to `sum` in chunks of [`CHECKSUM_CHUNK_SIZE`] bytes.
+///
+/// Returns `None` if `data` is longer than [`MAX_CHECKSUM_CHUNKS`] chunks.
+#[cfg_attr(feature = "inline", inline(always))]
+pub fn sum_bytes(sum: u32, data: &[u8]) -> Option<u32> {
+    if data.len() > CHECKSUM_CHUNK_SIZE * MAX_CHECKSUM_CHUNKS {
+        return None;
+    }
+
+    let mut sum = sum;
+    for i in 0..MAX_CHECKSUM_CHUNKS {
+        let start = i * CHECKSUM_CHUNK_SIZE;
+        if start >= data.len() {
+            break;
+        }
+        let chunk_end = core::cmp::min(start + CHECKSUM_CHUNK_SIZE, data.len());
+        sum = sum_chunk(sum, data.get(start..chunk_end)?);
+    }
+    Some(sum)
+}
+
+/// Sum of the IPv4 pseudo-header used by the TCP and UDP checksums.
+#[cfg_attr(feature = "inline", inline(always))]
+pub fn pseudo_header_ipv4(
+    source: &[u8; 4],
+    destination: &[u8; 4],
+    protocol: u8,
+    length: u16,
+) -> u32 {
+    let sum = u16::from_be_bytes([source[0], source[1]]) as u32
+        + u16::from_be_bytes([source[2], source[3]]) as u32
+        + u16::from_be_bytes([destination[0], destination[1]]) as u32
+        + u16::from_be_bytes([destination[2], destination[3]]) as u32
+        + protocol as u32
+        + length as u32;
+    fold(sum) as u32
+}
+
+/// Sum of the IPv6 pseudo-header used by the TCP, UDP and ICMPv6 checksums.
+#[cfg_attr(feature = "inline", inline(always))]
+pub fn pseudo_header_ipv6(
+    source: &[u8; 16],
+    destination: &[u8; 16],
+    next_header: u8,
+    length: u32,
+) -> u32 {
+    let mut sum = next_header as u32 + (length >> 16) + (length & 0xFFFF);
+    for i in 0..8 {
+        sum += u16::from_be_bytes([source[i * 2], source[i * 2 + 1]]) as u32;
+        sum += u16::from_be_bytes([destination[i * 2], destination[i * 2 + 1]]) as u32;
+    }
+    fold(sum) as u32
+}
+
+#[cfg(all(test, feature = "fmt"))]
+mod tests {
+    use super::*;
+
+    const SOURCE_IPV4: [u8; 4] = [192, 168, 1, 10];
+    const DESTINATION_IPV4: [u8; 4] = [192, 168, 1, 1];
+    const SOURCE_IPV6: [u8; 16] = [0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+    const DESTINATION_IPV6: [u8; 16] = [0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2];
+
+    #[test]
+    fn update_u16_rfc_1624_example() {
+        // RFC 1624 section 4: HC = 0xDD2F, m = 0x5555, m' = 0x3285 gives HC' = 0x0000.
+        assert_eq!(update_u16(0xDD2F, 0x5555, 0x3285), 0x0000);
+    }
+
+    #[test]
+    fn update_u16_matches_full_recompute() {
+        let mut data = [0x45, 0x00, 0x00, 0x54, 0x12, 0x34, 0x40, 0x00];
+        let checksum = !fold(sum_bytes(0, &data).unwrap());
+        data[4..6].copy_from_slice(&0xABCDu16.to_be_bytes());
+        assert_eq!(
+            update_u16(checksum, 0x1234, 0xABCD),
+            !fold(sum_bytes(0, &data).unwrap())
+        );
+    }
+
+    #[test]
+    fn sum_bytes_pads_odd_length() {
+        assert_eq!(sum_bytes(0, &[0x01, 0x02, 0x03]), Some(0x0402));
+        assert_eq!(sum_bytes(0, &[0xFF]), Some(0xFF00));
+        assert_eq!(sum_bytes(0x1234, &[]), Some(0x1234));
+    }
+
+    #[test]
+    fn sum_bytes_over_several_chunks() {
+        let mut data = [0u8; 131];
+        for (i, byte) in data.iter_mut().enumerate() {
+            *byte = (i * 7 + 3) as u8;
+        }
+        assert_eq!(sum_bytes(0, &data), Some(0x3269));
+    }
+
+    #[test]
+    fn sum_bytes_rejects_too_long_data() {
+        let data = [0u8; CHECKSUM_CHUNK_SIZE * MAX_CHECKSUM_CHUNKS + 1];
+        assert_eq!(sum_bytes(0, &data), None);
+        assert_eq!(sum_bytes(0, &data[1..]), Some(0));
+    }
+
+    #[test]
+    fn pseudo_header_ipv4_sum() {
+        assert_eq!(
+            pseudo_header_ipv4(&SOURCE_IPV4, &DESTINATION_IPV4, 17, 13),
+            0x837A
+        );
+    }
+
+    #[test]
+    fn pseudo_header_ipv6_sum() {
+        assert_eq!(
+            pseudo_header_ipv6(&SOURCE_IPV6, &DESTINATION_IPV6, 17, 13),
+            0x5B93
+        );
+        // The upper half of the length is part of the sum as well.
+        assert_eq!(
+            pseudo_header_ipv6(&SOURCE_IPV6, &DESTINATION_IPV6, 17, 0x1_000D),
+            0x5B94
+        );
+    }
+}
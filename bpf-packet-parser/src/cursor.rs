@@ -0,0 +1,117 @@
+mod error;
+
+pub use error::*;
+
+use crate::buffer::PacketBuffer;
+#[cfg(feature = "fmt")]
+use core::fmt::Debug;
+
+/// A packet buffer bundled with the `data_end` pointer of the program context.
+///
+/// Every access is checked against `end` before it is checked against the length of the
+/// buffer. The first check is what the BPF verifier needs to see to accept a packet access,
+/// the second one keeps the accesses panic free.
+///
+/// Parsers take the header they need from the front of the cursor and leave the cursor at
+/// their payload, the next layer continues from there.
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(PartialEq, Eq)]
+pub struct PacketCursor<B> {
+    buf: B,
+    end: usize,
+}
+
+impl<B: PacketBuffer> PacketCursor<B> {
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn new(buf: B, end: usize) -> Self {
+        PacketCursor { buf, end }
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_end(&self) -> usize {
+        self.end
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    /// The remaining buffer, the cursor is consumed.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn into_inner(self) -> B {
+        self.buf
+    }
+
+    /// Drops everything behind the first `len` bytes, e.g. the Ethernet padding behind an IP
+    /// packet. Does nothing if less than `len` bytes are left.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.buf.len() {
+            let (buf, _) = core::mem::take(&mut self.buf).split_buffer(len);
+            self.buf = buf;
+        }
+    }
+
+    /// Checks that at least `len` bytes are left.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn check_len(&self, len: usize) -> Result<(), CursorError> {
+        if self.end < self.buf.as_ptr() as usize + len {
+            return Err(CursorError::BpfOutOfBounds);
+        }
+
+        if self.buf.len() < len {
+            return Err(CursorError::TooShort {
+                size: self.buf.len(),
+                expected: len,
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns the first `N` bytes without moving the cursor.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn peek<const N: usize>(&self) -> Result<&[u8; N], CursorError> {
+        self.peek_at::<N>(0)
+    }
+
+    /// Returns `N` bytes starting at `offset` without moving the cursor.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn peek_at<const N: usize>(&self, offset: usize) -> Result<&[u8; N], CursorError> {
+        self.check_len(offset + N)?;
+
+        Ok(self
+            .buf
+            .get(offset..offset + N)
+            .ok_or(CursorError::OutOfBoundsBufferAccess)?
+            .try_into()?)
+    }
+
+    /// Skips the first `len` bytes.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn advance(&mut self, len: usize) -> Result<(), CursorError> {
+        self.split_header_len(len)?;
+        Ok(())
+    }
+
+    /// Splits off the first `N` bytes and moves the cursor behind them.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn split_header<const N: usize>(&mut self) -> Result<B, CursorError> {
+        self.split_header_len(N)
+    }
+
+    /// Like [`PacketCursor::split_header`] for headers whose length is only known at runtime.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn split_header_len(&mut self, len: usize) -> Result<B, CursorError> {
+        self.check_len(len)?;
+
+        let (header, payload) = core::mem::take(&mut self.buf).split_buffer(len);
+        self.buf = payload;
+        Ok(header)
+    }
+}
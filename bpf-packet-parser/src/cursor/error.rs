@@ -0,0 +1,44 @@
+use core::array::TryFromSliceError;
+#[cfg(feature = "fmt")]
+use core::fmt::{Debug, Display, Formatter};
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum CursorError {
+    TooShort { size: usize, expected: usize },
+    BpfOutOfBounds,
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for CursorError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for CursorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::TooShort { size, expected } => {
+                write!(
+                    f,
+                    "Buffer expected to be at least {expected} bytes, was: {size}"
+                )
+            }
+            Self::BpfOutOfBounds => {
+                write!(f, "Bpf out of bounds")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for CursorError {}
@@ -1,11 +1,38 @@
+use crate::arp::{ArpBpfCreationError, ArpError, ArpGetHeaderError, ArpSetHeaderError};
+use crate::cursor::CursorError;
 use crate::ethernet::EthernetError::{
-    BpfCreation, BpfWithVlanCreation, CutBothVlanTags, CutFirstVlanTag, GetFirstVlanParameter,
-    GetHeader, GetSecondVlanParameter, MatchEtherWithVlan,
+    BpfAutoVlanCreation, BpfCreation, BpfWithVlanCreation, CutBothVlanTags, CutFirstVlanTag,
+    GetFirstVlanParameter, GetHeader, GetLength, GetSecondVlanParameter, GetVlanTag,
+    LlcBpfCreation, MatchEtherWithVlan, PopMplsLabel, PushMplsLabel, PushVlanTag, SetHeader,
+    SnapBpfCreation,
 };
 use crate::ethernet::{
-    CutBothVlanTagsError, CutFirstVlanTagError, EthernetBpfCreationError,
-    EthernetBpfWithVlanCreationError, EthernetError, GetFirstVlanParameterError, GetHeaderError,
-    GetSecondVlanParameterError, MatchEtherWithVlanError,
+    CutBothVlanTagsError, CutFirstVlanTagError, EthernetBpfAutoVlanCreationError,
+    EthernetBpfCreationError, EthernetBpfWithVlanCreationError, EthernetError,
+    GetFirstVlanParameterError, GetHeaderError, GetLengthError, GetSecondVlanParameterError,
+    GetVlanTagError, LlcBpfCreationError, MatchEtherWithVlanError, PopMplsLabelError,
+    PushMplsLabelError, PushVlanTagError, SetHeaderError, SnapBpfCreationError,
+};
+use crate::geneve::{GeneveBpfCreationError, GeneveError, GeneveGetHeaderError, GeneveOptionError};
+use crate::gre::{
+    ErspanBpfCreationError, ErspanGetHeaderError, GreBpfCreationError, GreError, GreGetHeaderError,
+};
+use crate::gtpu::{GtpuBpfCreationError, GtpuError, GtpuGetHeaderError, GtpuInnerIpError};
+use crate::icmp::{IcmpBpfCreationError, IcmpError, IcmpGetHeaderError, IcmpQuotedHeaderError};
+use crate::icmpv6::{
+    Icmpv6BpfCreationError, Icmpv6Error, Icmpv6GetHeaderError, Icmpv6QuotedHeaderError, NdpError,
+    NdpOptionError,
+};
+use crate::ipv4::{Ipv4BpfCreationError, Ipv4Error, Ipv4GetHeaderError};
+use crate::ipv6::{Ipv6BpfCreationError, Ipv6Error, Ipv6ExtensionHeaderError, Ipv6GetHeaderError};
+use crate::mpls::{MplsBpfCreationError, MplsError, MplsGetHeaderError, MplsPayloadTypeError};
+use crate::pppoe::{PppoeBpfCreationError, PppoeError, PppoeGetHeaderError, PppoeTagError};
+use crate::tcp::{
+    TcpBpfCreationError, TcpError, TcpGetHeaderError, TcpOptionError, TcpSetOptionError,
+};
+use crate::udp::{UdpBpfCreationError, UdpChecksumError, UdpError, UdpGetHeaderError};
+use crate::vxlan::{
+    VxlanBpfCreationError, VxlanDecapError, VxlanEncapError, VxlanError, VxlanGetHeaderError,
 };
 #[cfg(feature = "fmt")]
 use core::fmt::{Debug, Display, Formatter};
@@ -13,20 +40,82 @@ use core::fmt::{Debug, Display, Formatter};
 #[cfg_attr(feature = "fmt", derive(Debug))]
 #[derive(Copy, Clone, Eq, PartialEq)]
 pub enum Error {
+    Arp(ArpError),
+    Cursor(CursorError),
     Ethernet(EthernetError),
+    Geneve(GeneveError),
+    Gre(GreError),
+    Gtpu(GtpuError),
+    Icmp(IcmpError),
+    Icmpv6(Icmpv6Error),
+    Ipv4(Ipv4Error),
+    Ipv6(Ipv6Error),
+    Mpls(MplsError),
+    Pppoe(PppoeError),
+    Tcp(TcpError),
+    Udp(UdpError),
+    Vxlan(VxlanError),
 }
 
 #[cfg(feature = "fmt")]
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         match self {
+            Error::Arp(err) => {
+                write!(f, "{err}")
+            }
+            Error::Cursor(err) => {
+                write!(f, "{err}")
+            }
             Error::Ethernet(err) => {
                 write!(f, "{err}")
             }
+            Error::Geneve(err) => {
+                write!(f, "{err}")
+            }
+            Error::Gre(err) => {
+                write!(f, "{err}")
+            }
+            Error::Gtpu(err) => {
+                write!(f, "{err}")
+            }
+            Error::Icmp(err) => {
+                write!(f, "{err}")
+            }
+            Error::Icmpv6(err) => {
+                write!(f, "{err}")
+            }
+            Error::Ipv4(err) => {
+                write!(f, "{err}")
+            }
+            Error::Ipv6(err) => {
+                write!(f, "{err}")
+            }
+            Error::Mpls(err) => {
+                write!(f, "{err}")
+            }
+            Error::Pppoe(err) => {
+                write!(f, "{err}")
+            }
+            Error::Tcp(err) => {
+                write!(f, "{err}")
+            }
+            Error::Udp(err) => {
+                write!(f, "{err}")
+            }
+            Error::Vxlan(err) => {
+                write!(f, "{err}")
+            }
         }
     }
 }
 
+impl From<CursorError> for Error {
+    fn from(value: CursorError) -> Self {
+        Self::Cursor(value)
+    }
+}
+
 impl From<EthernetError> for Error {
     fn from(value: EthernetError) -> Self {
         Self::Ethernet(value)
@@ -45,6 +134,12 @@ impl From<EthernetBpfWithVlanCreationError> for Error {
     }
 }
 
+impl From<EthernetBpfAutoVlanCreationError> for Error {
+    fn from(value: EthernetBpfAutoVlanCreationError) -> Self {
+        Self::Ethernet(BpfAutoVlanCreation(value))
+    }
+}
+
 impl From<MatchEtherWithVlanError> for Error {
     fn from(value: MatchEtherWithVlanError) -> Self {
         Self::Ethernet(MatchEtherWithVlan(value))
@@ -69,6 +164,12 @@ impl From<GetSecondVlanParameterError> for Error {
     }
 }
 
+impl From<GetVlanTagError> for Error {
+    fn from(value: GetVlanTagError) -> Self {
+        Self::Ethernet(GetVlanTag(value))
+    }
+}
+
 impl From<CutFirstVlanTagError> for Error {
     fn from(value: CutFirstVlanTagError) -> Self {
         Self::Ethernet(CutFirstVlanTag(value))
@@ -81,5 +182,383 @@ impl From<CutBothVlanTagsError> for Error {
     }
 }
 
+impl From<PushVlanTagError> for Error {
+    fn from(value: PushVlanTagError) -> Self {
+        Self::Ethernet(PushVlanTag(value))
+    }
+}
+
+impl From<PushMplsLabelError> for Error {
+    fn from(value: PushMplsLabelError) -> Self {
+        Self::Ethernet(PushMplsLabel(value))
+    }
+}
+
+impl From<PopMplsLabelError> for Error {
+    fn from(value: PopMplsLabelError) -> Self {
+        Self::Ethernet(PopMplsLabel(value))
+    }
+}
+
+impl From<SetHeaderError> for Error {
+    fn from(value: SetHeaderError) -> Self {
+        Self::Ethernet(SetHeader(value))
+    }
+}
+
+impl From<GetLengthError> for Error {
+    fn from(value: GetLengthError) -> Self {
+        Self::Ethernet(GetLength(value))
+    }
+}
+
+impl From<LlcBpfCreationError> for Error {
+    fn from(value: LlcBpfCreationError) -> Self {
+        Self::Ethernet(LlcBpfCreation(value))
+    }
+}
+
+impl From<SnapBpfCreationError> for Error {
+    fn from(value: SnapBpfCreationError) -> Self {
+        Self::Ethernet(SnapBpfCreation(value))
+    }
+}
+
+impl From<Ipv4Error> for Error {
+    fn from(value: Ipv4Error) -> Self {
+        Self::Ipv4(value)
+    }
+}
+
+impl From<Ipv4BpfCreationError> for Error {
+    fn from(value: Ipv4BpfCreationError) -> Self {
+        Self::Ipv4(Ipv4Error::BpfCreation(value))
+    }
+}
+
+impl From<Ipv4GetHeaderError> for Error {
+    fn from(value: Ipv4GetHeaderError) -> Self {
+        Self::Ipv4(Ipv4Error::GetHeader(value))
+    }
+}
+
+impl From<Ipv6Error> for Error {
+    fn from(value: Ipv6Error) -> Self {
+        Self::Ipv6(value)
+    }
+}
+
+impl From<Ipv6BpfCreationError> for Error {
+    fn from(value: Ipv6BpfCreationError) -> Self {
+        Self::Ipv6(Ipv6Error::BpfCreation(value))
+    }
+}
+
+impl From<Ipv6ExtensionHeaderError> for Error {
+    fn from(value: Ipv6ExtensionHeaderError) -> Self {
+        Self::Ipv6(Ipv6Error::ExtensionHeader(value))
+    }
+}
+
+impl From<Ipv6GetHeaderError> for Error {
+    fn from(value: Ipv6GetHeaderError) -> Self {
+        Self::Ipv6(Ipv6Error::GetHeader(value))
+    }
+}
+
+impl From<TcpError> for Error {
+    fn from(value: TcpError) -> Self {
+        Self::Tcp(value)
+    }
+}
+
+impl From<TcpBpfCreationError> for Error {
+    fn from(value: TcpBpfCreationError) -> Self {
+        Self::Tcp(TcpError::BpfCreation(value))
+    }
+}
+
+impl From<TcpGetHeaderError> for Error {
+    fn from(value: TcpGetHeaderError) -> Self {
+        Self::Tcp(TcpError::GetHeader(value))
+    }
+}
+
+impl From<TcpOptionError> for Error {
+    fn from(value: TcpOptionError) -> Self {
+        Self::Tcp(TcpError::Option(value))
+    }
+}
+
+impl From<TcpSetOptionError> for Error {
+    fn from(value: TcpSetOptionError) -> Self {
+        Self::Tcp(TcpError::SetOption(value))
+    }
+}
+
+impl From<UdpError> for Error {
+    fn from(value: UdpError) -> Self {
+        Self::Udp(value)
+    }
+}
+
+impl From<UdpBpfCreationError> for Error {
+    fn from(value: UdpBpfCreationError) -> Self {
+        Self::Udp(UdpError::BpfCreation(value))
+    }
+}
+
+impl From<UdpGetHeaderError> for Error {
+    fn from(value: UdpGetHeaderError) -> Self {
+        Self::Udp(UdpError::GetHeader(value))
+    }
+}
+
+impl From<UdpChecksumError> for Error {
+    fn from(value: UdpChecksumError) -> Self {
+        Self::Udp(UdpError::Checksum(value))
+    }
+}
+
+impl From<IcmpError> for Error {
+    fn from(value: IcmpError) -> Self {
+        Self::Icmp(value)
+    }
+}
+
+impl From<IcmpBpfCreationError> for Error {
+    fn from(value: IcmpBpfCreationError) -> Self {
+        Self::Icmp(IcmpError::BpfCreation(value))
+    }
+}
+
+impl From<IcmpGetHeaderError> for Error {
+    fn from(value: IcmpGetHeaderError) -> Self {
+        Self::Icmp(IcmpError::GetHeader(value))
+    }
+}
+
+impl From<IcmpQuotedHeaderError> for Error {
+    fn from(value: IcmpQuotedHeaderError) -> Self {
+        Self::Icmp(IcmpError::QuotedHeader(value))
+    }
+}
+
+impl From<Icmpv6Error> for Error {
+    fn from(value: Icmpv6Error) -> Self {
+        Self::Icmpv6(value)
+    }
+}
+
+impl From<Icmpv6BpfCreationError> for Error {
+    fn from(value: Icmpv6BpfCreationError) -> Self {
+        Self::Icmpv6(Icmpv6Error::BpfCreation(value))
+    }
+}
+
+impl From<Icmpv6GetHeaderError> for Error {
+    fn from(value: Icmpv6GetHeaderError) -> Self {
+        Self::Icmpv6(Icmpv6Error::GetHeader(value))
+    }
+}
+
+impl From<Icmpv6QuotedHeaderError> for Error {
+    fn from(value: Icmpv6QuotedHeaderError) -> Self {
+        Self::Icmpv6(Icmpv6Error::QuotedHeader(value))
+    }
+}
+
+impl From<NdpError> for Error {
+    fn from(value: NdpError) -> Self {
+        Self::Icmpv6(Icmpv6Error::Ndp(value))
+    }
+}
+
+impl From<NdpOptionError> for Error {
+    fn from(value: NdpOptionError) -> Self {
+        Self::Icmpv6(Icmpv6Error::NdpOption(value))
+    }
+}
+
+impl From<ArpError> for Error {
+    fn from(value: ArpError) -> Self {
+        Self::Arp(value)
+    }
+}
+
+impl From<ArpBpfCreationError> for Error {
+    fn from(value: ArpBpfCreationError) -> Self {
+        Self::Arp(ArpError::BpfCreation(value))
+    }
+}
+
+impl From<ArpGetHeaderError> for Error {
+    fn from(value: ArpGetHeaderError) -> Self {
+        Self::Arp(ArpError::GetHeader(value))
+    }
+}
+
+impl From<ArpSetHeaderError> for Error {
+    fn from(value: ArpSetHeaderError) -> Self {
+        Self::Arp(ArpError::SetHeader(value))
+    }
+}
+
+impl From<MplsError> for Error {
+    fn from(value: MplsError) -> Self {
+        Self::Mpls(value)
+    }
+}
+
+impl From<MplsBpfCreationError> for Error {
+    fn from(value: MplsBpfCreationError) -> Self {
+        Self::Mpls(MplsError::BpfCreation(value))
+    }
+}
+
+impl From<MplsGetHeaderError> for Error {
+    fn from(value: MplsGetHeaderError) -> Self {
+        Self::Mpls(MplsError::GetHeader(value))
+    }
+}
+
+impl From<MplsPayloadTypeError> for Error {
+    fn from(value: MplsPayloadTypeError) -> Self {
+        Self::Mpls(MplsError::PayloadType(value))
+    }
+}
+
+impl From<PppoeError> for Error {
+    fn from(value: PppoeError) -> Self {
+        Self::Pppoe(value)
+    }
+}
+
+impl From<PppoeBpfCreationError> for Error {
+    fn from(value: PppoeBpfCreationError) -> Self {
+        Self::Pppoe(PppoeError::BpfCreation(value))
+    }
+}
+
+impl From<PppoeGetHeaderError> for Error {
+    fn from(value: PppoeGetHeaderError) -> Self {
+        Self::Pppoe(PppoeError::GetHeader(value))
+    }
+}
+
+impl From<PppoeTagError> for Error {
+    fn from(value: PppoeTagError) -> Self {
+        Self::Pppoe(PppoeError::Tag(value))
+    }
+}
+
+impl From<VxlanError> for Error {
+    fn from(value: VxlanError) -> Self {
+        Self::Vxlan(value)
+    }
+}
+
+impl From<VxlanBpfCreationError> for Error {
+    fn from(value: VxlanBpfCreationError) -> Self {
+        Self::Vxlan(VxlanError::BpfCreation(value))
+    }
+}
+
+impl From<VxlanGetHeaderError> for Error {
+    fn from(value: VxlanGetHeaderError) -> Self {
+        Self::Vxlan(VxlanError::GetHeader(value))
+    }
+}
+
+impl From<VxlanDecapError> for Error {
+    fn from(value: VxlanDecapError) -> Self {
+        Self::Vxlan(VxlanError::Decap(value))
+    }
+}
+
+impl From<VxlanEncapError> for Error {
+    fn from(value: VxlanEncapError) -> Self {
+        Self::Vxlan(VxlanError::Encap(value))
+    }
+}
+
+impl From<GeneveError> for Error {
+    fn from(value: GeneveError) -> Self {
+        Self::Geneve(value)
+    }
+}
+
+impl From<GeneveBpfCreationError> for Error {
+    fn from(value: GeneveBpfCreationError) -> Self {
+        Self::Geneve(GeneveError::BpfCreation(value))
+    }
+}
+
+impl From<GeneveGetHeaderError> for Error {
+    fn from(value: GeneveGetHeaderError) -> Self {
+        Self::Geneve(GeneveError::GetHeader(value))
+    }
+}
+
+impl From<GeneveOptionError> for Error {
+    fn from(value: GeneveOptionError) -> Self {
+        Self::Geneve(GeneveError::Option(value))
+    }
+}
+
+impl From<GreError> for Error {
+    fn from(value: GreError) -> Self {
+        Self::Gre(value)
+    }
+}
+
+impl From<GreBpfCreationError> for Error {
+    fn from(value: GreBpfCreationError) -> Self {
+        Self::Gre(GreError::BpfCreation(value))
+    }
+}
+
+impl From<GreGetHeaderError> for Error {
+    fn from(value: GreGetHeaderError) -> Self {
+        Self::Gre(GreError::GetHeader(value))
+    }
+}
+
+impl From<ErspanBpfCreationError> for Error {
+    fn from(value: ErspanBpfCreationError) -> Self {
+        Self::Gre(GreError::ErspanBpfCreation(value))
+    }
+}
+
+impl From<ErspanGetHeaderError> for Error {
+    fn from(value: ErspanGetHeaderError) -> Self {
+        Self::Gre(GreError::ErspanGetHeader(value))
+    }
+}
+
+impl From<GtpuError> for Error {
+    fn from(value: GtpuError) -> Self {
+        Self::Gtpu(value)
+    }
+}
+
+impl From<GtpuBpfCreationError> for Error {
+    fn from(value: GtpuBpfCreationError) -> Self {
+        Self::Gtpu(GtpuError::BpfCreation(value))
+    }
+}
+
+impl From<GtpuGetHeaderError> for Error {
+    fn from(value: GtpuGetHeaderError) -> Self {
+        Self::Gtpu(GtpuError::GetHeader(value))
+    }
+}
+
+impl From<GtpuInnerIpError> for Error {
+    fn from(value: GtpuInnerIpError) -> Self {
+        Self::Gtpu(GtpuError::InnerIp(value))
+    }
+}
+
 #[cfg(feature = "error_trait")]
 impl core::error::Error for Error {}
@@ -1,9 +1,18 @@
 mod error;
 mod header_values;
+mod llc;
+mod mac_addr;
+mod vlan;
 
 pub use error::*;
 pub use header_values::*;
+pub use llc::*;
+pub use mac_addr::*;
+pub use vlan::*;
 
+use crate::buffer::PacketBuffer;
+use crate::cursor::PacketCursor;
+use crate::mpls::{guess_payload_type, MplsLabel, MplsPayloadType, MPLS_LABEL_SIZE};
 #[cfg(feature = "fmt")]
 use core::fmt::Debug;
 
@@ -30,6 +39,11 @@ pub static SECOND_VLAN_TAG_ETHER_TYPE_START: usize = 16;
 pub static SECOND_VLAN_TAG_ETHER_TYPE_END: usize = 18;
 pub static SECOND_VLAN_TAG_PARAM_START: usize = 18;
 pub static SECOND_VLAN_TAG_PARAM_END: usize = 20;
+pub static VLAN_TAG_SIZE: usize = 4;
+/// Maximum number of stacked VLAN tags the parser walks, see
+/// [bounded loops](crate#bounded-loops). Frames with more tags than [`Vlan`] has variants are
+/// parsed as well, see [`EtherFrame::get_vlan_tag_count`].
+pub const MAX_VLAN_TAGS: usize = 3;
 
 #[cfg_attr(feature = "fmt", derive(Debug))]
 #[derive(Copy, Clone, PartialEq, Eq)]
@@ -39,25 +53,61 @@ pub static SECOND_VLAN_TAG_PARAM_END: usize = 20;
 pub enum Vlan {
     SingleTagged = 1,
     DoubleTagged = 2,
+    TripleTagged = 3,
 }
 
+impl Vlan {
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_tag_count(tag_count: usize) -> Option<Vlan> {
+        match tag_count {
+            1 => Some(Vlan::SingleTagged),
+            2 => Some(Vlan::DoubleTagged),
+            3 => Some(Vlan::TripleTagged),
+            _ => None,
+        }
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_tag_count(&self) -> usize {
+        *self as usize
+    }
+}
+
+/// Ethernet II or IEEE 802.3 frame header, optionally with VLAN tags.
+///
+/// `B` is `&[u8]` for a read-only view or `&mut [u8]` to also modify the frame, see
+/// [`PacketBuffer`].
 #[cfg_attr(feature = "fmt", derive(Debug))]
 #[derive(PartialEq, Eq)]
-pub struct EtherFrame<'a> {
-    // size of 1B
-    vlan: Option<Vlan>,
+pub struct EtherFrame<B> {
+    // size of 8B, usize keeps the offset math verifier friendly, see `Vlan`
+    vlan_tag_count: usize,
     // size of 2B
     ether_type: EtherType,
     // size of 16B
-    headers: &'a mut [u8],
+    headers: B,
 }
 
-impl<'a> EtherFrame<'a> {
+impl<B: PacketBuffer> EtherFrame<B> {
     #[cfg_attr(feature = "inline", inline(always))]
-    pub fn new_bpf(
-        buf: &'a mut [u8],
+    pub fn new_bpf(buf: B, end: usize) -> Result<(Self, B), EthernetBpfCreationError> {
+        Self::new_bpf_impl(buf, end, false)
+    }
+
+    /// Like [`EtherFrame::new_bpf`], but unrecognized ether types don't fail. They are stored
+    /// as [`EtherType::EtherTypeErrorVariant`] and the raw value stays available through
+    /// [`EtherFrame::get_raw_ether_type`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn new_bpf_lenient(buf: B, end: usize) -> Result<(Self, B), EthernetBpfCreationError> {
+        Self::new_bpf_impl(buf, end, true)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn new_bpf_impl(
+        buf: B,
         end: usize,
-    ) -> Result<(Self, &mut [u8]), EthernetBpfCreationError> {
+        lenient: bool,
+    ) -> Result<(Self, B), EthernetBpfCreationError> {
         // We read at most the first 14 bytes of the frame:
         // 6 bytes dst MAC
         // 6 bytes src MAC
@@ -65,38 +115,46 @@ impl<'a> EtherFrame<'a> {
         // ------------------
         // 14 bytes total
 
-        if end < buf.as_ptr() as usize + 14 {
-            return Err(EthernetBpfCreationError::BpfOutOfBounds);
-        }
-
-        if buf.len() < 14 {
-            return Err(EthernetBpfCreationError::FrameTooShort { size: buf.len() });
-        }
+        let mut cursor = PacketCursor::new(buf, end);
+        let value = u16::from_be_bytes(*cursor.peek_at::<2>(ETHER_TYPE_START)?);
 
-        let value = u16::from_be_bytes(
-            buf.get(ETHER_TYPE_START..ETHER_TYPE_END)
-                .ok_or(EthernetBpfCreationError::OutOfBoundsBufferAccess)?
-                .try_into()?,
-        );
-
-        let ether_type = EtherType::lookup(value)?;
-        let (headers, payload) = buf.split_at_mut(14);
+        let ether_type = Self::lookup_ether_type(value, lenient)?;
+        let headers = cursor.split_header::<14>()?;
 
         Ok((
             EtherFrame {
-                vlan: None,
+                vlan_tag_count: 0,
                 ether_type,
                 headers,
             },
-            payload,
+            cursor.into_inner(),
         ))
     }
 
     #[cfg_attr(feature = "inline", inline(always))]
     pub fn new_bpf_with_vlan(
-        buf: &'a mut [u8],
+        buf: B,
+        end: usize,
+    ) -> Result<(Self, B), EthernetBpfWithVlanCreationError> {
+        Self::new_bpf_with_vlan_impl(buf, end, false)
+    }
+
+    /// Like [`EtherFrame::new_bpf_with_vlan`], but unrecognized ether types don't fail, see
+    /// [`EtherFrame::new_bpf_lenient`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn new_bpf_with_vlan_lenient(
+        buf: B,
+        end: usize,
+    ) -> Result<(Self, B), EthernetBpfWithVlanCreationError> {
+        Self::new_bpf_with_vlan_impl(buf, end, true)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn new_bpf_with_vlan_impl(
+        buf: B,
         end: usize,
-    ) -> Result<(Self, &mut [u8]), EthernetBpfWithVlanCreationError> {
+        lenient: bool,
+    ) -> Result<(Self, B), EthernetBpfWithVlanCreationError> {
         // We read at most the first 22 bytes of the frame:
         // 6 bytes dst MAC
         // 6 bytes src MAC
@@ -105,108 +163,905 @@ impl<'a> EtherFrame<'a> {
         // 2 bytes ether type
         // ------------------
         // 22 bytes total
+        // Further VLAN tags up to MAX_VLAN_TAGS are bounds checked while walking the stack.
 
-        if end < buf.as_ptr() as usize + 22 {
-            return Err(EthernetBpfWithVlanCreationError::BpfOutOfBounds);
-        }
+        let mut cursor = PacketCursor::new(buf, end);
+        cursor.check_len(22)?;
 
-        if buf.len() < 22 {
-            return Err(EthernetBpfWithVlanCreationError::FrameTooShort { size: buf.len() });
-        }
+        let (ether_type, vlan_tag_len) = Self::match_ether_type_with_vlan(&cursor, 0, lenient)?;
 
-        let (ether_type, vlan_tag_len, vlan) = Self::match_ether_type_with_vlan(buf)?;
-
-        let (headers, payload) = buf.split_at_mut(PAYLOAD_START_NO_VLAN + vlan_tag_len);
+        let headers = cursor.split_header_len(PAYLOAD_START_NO_VLAN + vlan_tag_len)?;
 
         Ok((
             EtherFrame {
-                vlan,
+                vlan_tag_count: vlan_tag_len / VLAN_TAG_SIZE,
                 ether_type,
                 headers,
             },
-            payload,
+            cursor.into_inner(),
         ))
     }
 
+    /// Detects VLAN tagging from the TPIDs and only requires as many bytes as the detected
+    /// frame layout has: 14 bytes for untagged frames plus 4 bytes for every VLAN tag.
+    ///
+    /// See [`is_vlan_tpid`] for the recognized TPIDs, at most [`MAX_VLAN_TAGS`] tags are
+    /// parsed.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn new_bpf_auto_vlan(
+        buf: B,
+        end: usize,
+    ) -> Result<(Self, B), EthernetBpfAutoVlanCreationError> {
+        Self::new_bpf_auto_vlan_impl(buf, end, false)
+    }
+
+    /// Like [`EtherFrame::new_bpf_auto_vlan`], but unrecognized ether types don't fail, see
+    /// [`EtherFrame::new_bpf_lenient`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn new_bpf_auto_vlan_lenient(
+        buf: B,
+        end: usize,
+    ) -> Result<(Self, B), EthernetBpfAutoVlanCreationError> {
+        Self::new_bpf_auto_vlan_impl(buf, end, true)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn new_bpf_auto_vlan_impl(
+        buf: B,
+        end: usize,
+        lenient: bool,
+    ) -> Result<(Self, B), EthernetBpfAutoVlanCreationError> {
+        let mut cursor = PacketCursor::new(buf, end);
+        let frame = Self::from_cursor_impl(&mut cursor, lenient)?;
+        Ok((frame, cursor.into_inner()))
+    }
+
+    /// Takes the frame header from the front of `cursor` and leaves the cursor at the payload.
+    /// VLAN tags are detected like in [`EtherFrame::new_bpf_auto_vlan`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_cursor(
+        cursor: &mut PacketCursor<B>,
+    ) -> Result<Self, EthernetBpfAutoVlanCreationError> {
+        Self::from_cursor_impl(cursor, false)
+    }
+
+    /// Like [`EtherFrame::from_cursor`], but unrecognized ether types don't fail, see
+    /// [`EtherFrame::new_bpf_lenient`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_cursor_lenient(
+        cursor: &mut PacketCursor<B>,
+    ) -> Result<Self, EthernetBpfAutoVlanCreationError> {
+        Self::from_cursor_impl(cursor, true)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn from_cursor_impl(
+        cursor: &mut PacketCursor<B>,
+        lenient: bool,
+    ) -> Result<Self, EthernetBpfAutoVlanCreationError> {
+        // We read at least the first 14 bytes of the frame:
+        // 6 bytes dst MAC
+        // 6 bytes src MAC
+        // 2 bytes ether type
+        // ------------------
+        // 14 bytes total
+        // Every VLAN tag adds 4 bytes which are bounds checked while walking the stack.
+
+        let (ether_type, vlan_tag_len) = Self::match_ether_type_with_vlan(cursor, 0, lenient)?;
+
+        let headers = cursor.split_header_len(PAYLOAD_START_NO_VLAN + vlan_tag_len)?;
+
+        Ok(EtherFrame {
+            vlan_tag_count: vlan_tag_len / VLAN_TAG_SIZE,
+            ether_type,
+            headers,
+        })
+    }
+
+    /// `None` for untagged frames and for frames with more tags than [`Vlan`] has variants,
+    /// see [`EtherFrame::get_vlan_tag_count`].
     #[cfg_attr(feature = "inline", inline(always))]
     pub fn get_vlan(&self) -> Option<Vlan> {
-        self.vlan
+        Vlan::from_tag_count(self.vlan_tag_count)
+    }
+
+    /// Returns the outermost VLAN tag, whatever its TPID.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_first_vlan_parameter(&self) -> Result<VlanTag, GetFirstVlanParameterError> {
+        if self.vlan_tag_count == 0 {
+            return Err(GetFirstVlanParameterError::NotVlanTagged);
+        }
+
+        Ok(VlanTag::from_bytes(
+            self.headers
+                .get(FIRST_VLAN_TAG_ETHER_TYPE_START..FIRST_VLAN_TAG_PARAM_END)
+                .ok_or(GetFirstVlanParameterError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        ))
     }
 
+    /// Returns the second VLAN tag counting from the outermost one, e.g. the C-tag of a QinQ
+    /// frame.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_second_vlan_parameter(&self) -> Result<VlanTag, GetSecondVlanParameterError> {
+        match self.vlan_tag_count {
+            0 => return Err(GetSecondVlanParameterError::NotVlanTagged),
+            1 => return Err(GetSecondVlanParameterError::NotVlanDoubleTagged),
+            _ => {}
+        }
+
+        Ok(VlanTag::from_bytes(
+            self.headers
+                .get(SECOND_VLAN_TAG_ETHER_TYPE_START..SECOND_VLAN_TAG_PARAM_END)
+                .ok_or(GetSecondVlanParameterError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        ))
+    }
+
+    /// Returns the VLAN tag at `index`, counting from the outermost tag at index 0.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_vlan_tag(&self, index: usize) -> Result<VlanTag, GetVlanTagError> {
+        let tag_count = self.get_vlan_tag_count();
+        if index >= tag_count {
+            return Err(GetVlanTagError::NoSuchVlanTag { index, tag_count });
+        }
+
+        let tag_start = FIRST_VLAN_TAG_ETHER_TYPE_START + index * VLAN_TAG_SIZE;
+        Ok(VlanTag::from_bytes(
+            self.headers
+                .get(tag_start..tag_start + VLAN_TAG_SIZE)
+                .ok_or(GetVlanTagError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        ))
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_vlan_tag_count(&self) -> usize {
+        self.vlan_tag_count
+    }
+
+    /// Walks the VLAN tag stack starting at the outer ether type of the frame at `offset`.
+    /// Every TPID accepted by [`is_vlan_tpid`] counts as a tag, the walk stops at the first
+    /// other value or after [`MAX_VLAN_TAGS`] tags.
+    ///
+    /// Returns the ether type behind the tags and the length of all tags. If `lenient` is
+    /// set, unrecognized ether types are returned as [`EtherType::EtherTypeErrorVariant`].
     #[cfg_attr(feature = "inline", inline(always))]
     fn match_ether_type_with_vlan(
-        frame: &[u8],
-    ) -> Result<(EtherType, usize, Option<Vlan>), MatchEtherWithVlanError> {
-        let mut vlan = None;
+        cursor: &PacketCursor<B>,
+        offset: usize,
+        lenient: bool,
+    ) -> Result<(EtherType, usize), MatchEtherWithVlanError> {
         // Length of the VLAN tags.
         let mut vlan_tag_len = 0;
+        let mut value = u16::from_be_bytes(*cursor.peek_at::<2>(offset + ETHER_TYPE_START)?);
 
-        let ether_type = match <&[u8] as TryInto<&[u8; 10]>>::try_into(
-            frame
-                .get(ETHER_TYPE_START..ETHER_TYPE_START + 10)
-                .ok_or(MatchEtherWithVlanError::OutOfBoundsBufferAccess)?,
-        )? {
-            [0x81, 0x00, _, _, x, y, _, _, _, _] => {
-                vlan = Some(Vlan::SingleTagged);
-                vlan_tag_len = 4;
-
-                EtherType::lookup(u16::from_be_bytes([*x, *y]))?
+        for _ in 0..MAX_VLAN_TAGS {
+            if !is_vlan_tpid(value) {
+                break;
             }
-            [0x88, 0xA8, _, _, 0x81, 0x00, _, _, x, y] => {
-                vlan = Some(Vlan::DoubleTagged);
-                vlan_tag_len = 8;
+            vlan_tag_len += VLAN_TAG_SIZE;
+            value =
+                u16::from_be_bytes(*cursor.peek_at::<2>(offset + ETHER_TYPE_START + vlan_tag_len)?);
+        }
 
-                EtherType::lookup(u16::from_be_bytes([*x, *y]))?
-            }
-            [x, y, _, _, _, _, _, _, _, _] => EtherType::lookup(u16::from_be_bytes([*x, *y]))?,
-        };
-        Ok((ether_type, vlan_tag_len, vlan))
+        if is_vlan_tpid(value) {
+            return Err(MatchEtherWithVlanError::TooManyVlanTags);
+        }
+
+        let ether_type = Self::lookup_ether_type(value, lenient)?;
+        Ok((ether_type, vlan_tag_len))
     }
 
+    /// Maps IEEE 802.3 length values to [`EtherType::Ieee8023Length`] and looks up all other
+    /// values, see [`EtherFrame::new_bpf_lenient`] for `lenient`.
     #[cfg_attr(feature = "inline", inline(always))]
-    pub fn get_destination(&self) -> Result<&[u8; 6], GetHeaderError> {
-        Ok(self
-            .headers
-            .get(DESTINATION_MAC_START..DESTINATION_MAC_END)
-            .ok_or(GetHeaderError::OutOfBoundsBufferAccess)?
-            .try_into()?)
+    fn lookup_ether_type(value: u16, lenient: bool) -> Result<EtherType, EtherTypeParsingError> {
+        if value <= IEEE_802_3_MAX_LENGTH {
+            Ok(EtherType::Ieee8023Length)
+        } else if lenient {
+            Ok(EtherType::lookup_lenient(value))
+        } else {
+            EtherType::lookup(value)
+        }
     }
 
     #[cfg_attr(feature = "inline", inline(always))]
-    pub fn get_source(&self) -> Result<&[u8; 6], GetHeaderError> {
+    pub fn get_destination(&self) -> Result<MacAddr<'_>, GetHeaderError> {
+        Ok(MacAddr::new(
+            self.headers
+                .get(DESTINATION_MAC_START..DESTINATION_MAC_END)
+                .ok_or(GetHeaderError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        ))
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_source(&self) -> Result<MacAddr<'_>, GetHeaderError> {
+        Ok(MacAddr::new(
+            self.headers
+                .get(SOURCE_MAC_START..SOURCE_MAC_END)
+                .ok_or(GetHeaderError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        ))
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_typed_ether_type(&self) -> EtherType {
+        self.ether_type
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_ether_type(&self) -> Result<&[u8; 2], GetHeaderError> {
+        let ether_type_start = self.ether_type_start();
         Ok(self
             .headers
-            .get(SOURCE_MAC_START..SOURCE_MAC_END)
+            .get(ether_type_start..ether_type_start + 2)
             .ok_or(GetHeaderError::OutOfBoundsBufferAccess)?
             .try_into()?)
     }
 
+    /// The ether type as number, also for ether types without an [`EtherType`] variant.
     #[cfg_attr(feature = "inline", inline(always))]
-    pub fn get_typed_ether_type(&self) -> EtherType {
-        self.ether_type
+    pub fn get_raw_ether_type(&self) -> Result<u16, GetHeaderError> {
+        Ok(u16::from_be_bytes(*self.get_ether_type()?))
     }
 
+    /// The IEEE 802.3 payload length of frames with [`EtherType::Ieee8023Length`].
     #[cfg_attr(feature = "inline", inline(always))]
-    pub fn get_ether_type(&self) -> Result<&[u8; 2], GetHeaderError> {
-        if let Some(vlan_tag) = self.vlan {
-            match vlan_tag {
-                Vlan::SingleTagged => Ok(self
-                    .headers
-                    .get(SINGLE_TAGGED_ETHER_TYPE_START..SINGLE_TAGGED_ETHER_TYPE_END)
-                    .ok_or(GetHeaderError::OutOfBoundsBufferAccess)?
-                    .try_into()?),
-                Vlan::DoubleTagged => Ok(self
-                    .headers
-                    .get(DOUBLE_TAGGED_ETHER_TYPE_START..DOUBLE_TAGGED_ETHER_TYPE_END)
-                    .ok_or(GetHeaderError::OutOfBoundsBufferAccess)?
-                    .try_into()?),
+    pub fn get_length(&self) -> Result<u16, GetLengthError> {
+        let ether_type_start = self.ether_type_start();
+        let value = u16::from_be_bytes(
+            self.headers
+                .get(ether_type_start..ether_type_start + 2)
+                .ok_or(GetLengthError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        );
+
+        if value > IEEE_802_3_MAX_LENGTH {
+            return Err(GetLengthError::NotIeee8023Frame { ether_type: value });
+        }
+        Ok(value)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn ether_type_start(&self) -> usize {
+        ETHER_TYPE_START + self.get_vlan_tag_count() * VLAN_TAG_SIZE
+    }
+}
+
+impl<'a> EtherFrame<&'a mut [u8]> {
+    /// Pushes `tag` in front of an existing frame.
+    ///
+    /// `buf` has to start with `VLAN_TAG_SIZE` bytes of headroom, e.g. made by calling
+    /// `bpf_xdp_adjust_head` with `-4`, directly followed by the original frame. The MAC
+    /// addresses are moved into the headroom and the tag is written behind them.
+    ///
+    /// The TPID of `tag` has to be recognized by [`is_vlan_tpid`] and the resulting frame may
+    /// carry at most [`MAX_VLAN_TAGS`] tags. Unrecognized inner ether types are tagged as well,
+    /// see [`EtherFrame::new_bpf_lenient`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn push_vlan_tag(
+        buf: &'a mut [u8],
+        end: usize,
+        tag: VlanTag,
+    ) -> Result<(Self, &'a mut [u8]), PushVlanTagError> {
+        // We read at most the first 18 bytes of the new frame plus 4 bytes for every VLAN tag
+        // of the original frame:
+        // 4 bytes headroom
+        // 6 bytes dst MAC
+        // 6 bytes src MAC
+        // 2 bytes ether type
+        // ------------------
+        // 18 bytes total
+
+        if !is_vlan_tpid(tag.get_tpid()) {
+            return Err(PushVlanTagError::UnsupportedTpid {
+                tpid: tag.get_tpid(),
+            });
+        }
+
+        let mut cursor = PacketCursor::new(buf, end);
+        let (ether_type, vlan_tag_len) =
+            Self::match_ether_type_with_vlan(&cursor, VLAN_TAG_SIZE, true)?;
+
+        let vlan_tag_count = vlan_tag_len / VLAN_TAG_SIZE + 1;
+        if vlan_tag_count > MAX_VLAN_TAGS {
+            return Err(PushVlanTagError::TooManyVlanTags);
+        }
+
+        let headers =
+            cursor.split_header_len(VLAN_TAG_SIZE + PAYLOAD_START_NO_VLAN + vlan_tag_len)?;
+        let macs: [u8; 12] = headers
+            .get(DESTINATION_MAC_START + VLAN_TAG_SIZE..SOURCE_MAC_END + VLAN_TAG_SIZE)
+            .ok_or(PushVlanTagError::OutOfBoundsBufferAccess)?
+            .try_into()?;
+        headers
+            .get_mut(DESTINATION_MAC_START..SOURCE_MAC_END)
+            .ok_or(PushVlanTagError::OutOfBoundsBufferAccess)?
+            .copy_from_slice(&macs);
+        headers
+            .get_mut(FIRST_VLAN_TAG_ETHER_TYPE_START..FIRST_VLAN_TAG_PARAM_END)
+            .ok_or(PushVlanTagError::OutOfBoundsBufferAccess)?
+            .copy_from_slice(&tag.to_bytes());
+
+        Ok((
+            EtherFrame {
+                vlan_tag_count,
+                ether_type,
+                headers,
+            },
+            cursor.into_inner(),
+        ))
+    }
+
+    /// Removes the outer VLAN tag by moving both MAC addresses 4 bytes forward.
+    ///
+    /// Returns the offset of the new frame start relative to the old one, this is the value
+    /// to pass to `bpf_xdp_adjust_head`.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn cut_first_vlan_tag(&mut self) -> Result<usize, CutFirstVlanTagError> {
+        if self.vlan_tag_count == 0 {
+            return Err(CutFirstVlanTagError::NotVlanTagged);
+        }
+
+        self.move_macs_forward(VLAN_TAG_SIZE)
+            .ok_or(CutFirstVlanTagError::OutOfBoundsBufferAccess)?;
+        self.vlan_tag_count -= 1;
+
+        Ok(VLAN_TAG_SIZE)
+    }
+
+    /// Removes the two outer VLAN tags by moving both MAC addresses 8 bytes forward.
+    ///
+    /// Returns the offset of the new frame start relative to the old one, this is the value
+    /// to pass to `bpf_xdp_adjust_head`.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn cut_both_vlan_tags(&mut self) -> Result<usize, CutBothVlanTagsError> {
+        match self.vlan_tag_count {
+            0 => return Err(CutBothVlanTagsError::NotVlanTagged),
+            1 => return Err(CutBothVlanTagsError::NotVlanDoubleTagged),
+            _ => {}
+        }
+
+        self.move_macs_forward(2 * VLAN_TAG_SIZE)
+            .ok_or(CutBothVlanTagsError::OutOfBoundsBufferAccess)?;
+        self.vlan_tag_count -= 2;
+
+        Ok(2 * VLAN_TAG_SIZE)
+    }
+
+    /// Pushes `label` on top of the MPLS label stack of an existing frame.
+    ///
+    /// `buf` has to start with `MPLS_LABEL_SIZE` bytes of headroom, e.g. made by calling
+    /// `bpf_xdp_adjust_head` with `-4`, directly followed by the original frame. The MAC
+    /// addresses, VLAN tags and ether type are moved into the headroom and the label is
+    /// written behind them.
+    ///
+    /// Frames which don't carry MPLS yet get the ether type [`EtherType::MplsUnicast`] and
+    /// `label` becomes the bottom of the stack. The bottom of stack bit of `label` is set
+    /// accordingly. The returned payload starts with the new label.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn push_mpls_label(
+        buf: &'a mut [u8],
+        end: usize,
+        label: MplsLabel,
+    ) -> Result<(Self, &'a mut [u8]), PushMplsLabelError> {
+        // We read at most the first 18 bytes of the new frame plus 4 bytes for every VLAN tag
+        // of the original frame:
+        // 4 bytes headroom
+        // 6 bytes dst MAC
+        // 6 bytes src MAC
+        // 2 bytes ether type
+        // ------------------
+        // 18 bytes total
+
+        let cursor = PacketCursor::new(buf, end);
+        let (ether_type, vlan_tag_len) =
+            Self::match_ether_type_with_vlan(&cursor, MPLS_LABEL_SIZE, false)?;
+
+        let (ether_type, label) = match ether_type {
+            EtherType::MplsUnicast | EtherType::MplsMulticast => {
+                (ether_type, label.with_bottom_of_stack(false))
+            }
+            EtherType::Ieee8023Length => return Err(PushMplsLabelError::Ieee8023Frame),
+            _ => (EtherType::MplsUnicast, label.with_bottom_of_stack(true)),
+        };
+
+        let headers_len = PAYLOAD_START_NO_VLAN + vlan_tag_len;
+        cursor.check_len(MPLS_LABEL_SIZE + headers_len)?;
+        let buf = cursor.into_inner();
+
+        let moved = buf
+            .get_mut(..MPLS_LABEL_SIZE + headers_len)
+            .ok_or(PushMplsLabelError::OutOfBoundsBufferAccess)?;
+        moved.copy_within(MPLS_LABEL_SIZE.., 0);
+        moved
+            .get_mut(headers_len - 2..headers_len)
+            .ok_or(PushMplsLabelError::OutOfBoundsBufferAccess)?
+            .copy_from_slice(&(ether_type as u16).to_be_bytes());
+        moved
+            .get_mut(headers_len..headers_len + MPLS_LABEL_SIZE)
+            .ok_or(PushMplsLabelError::OutOfBoundsBufferAccess)?
+            .copy_from_slice(&label.to_bytes());
+
+        // `check_len` above guarantees that `buf` is longer than `headers_len`.
+        let (headers, payload) = buf.split_buffer(headers_len);
+        Ok((
+            EtherFrame {
+                vlan_tag_count: vlan_tag_len / VLAN_TAG_SIZE,
+                ether_type,
+                headers,
+            },
+            payload,
+        ))
+    }
+
+    /// Removes the top label of the MPLS label stack by moving the MAC addresses, VLAN tags and
+    /// ether type 4 bytes forward.
+    ///
+    /// The new frame starts `MPLS_LABEL_SIZE` bytes behind `buf`, this is the value to pass to
+    /// `bpf_xdp_adjust_head`. If the removed label was the bottom of the stack, the ether type
+    /// is set to [`EtherType::IpV4`] or [`EtherType::IpV6`] as guessed by
+    /// [`guess_payload_type`], other payloads fail. TTLs are not touched.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn pop_mpls_label(
+        buf: &'a mut [u8],
+        end: usize,
+    ) -> Result<(Self, &'a mut [u8]), PopMplsLabelError> {
+        // We read at least the first 19 bytes of the frame plus 4 bytes for every VLAN tag:
+        // 6 bytes dst MAC
+        // 6 bytes src MAC
+        // 2 bytes ether type
+        // 4 bytes MPLS label
+        // 1 byte payload, only read for the bottom of stack label
+        // ------------------
+        // 19 bytes total
+
+        let cursor = PacketCursor::new(buf, end);
+        let (ether_type, vlan_tag_len) = Self::match_ether_type_with_vlan(&cursor, 0, false)?;
+        if ether_type != EtherType::MplsUnicast && ether_type != EtherType::MplsMulticast {
+            return Err(PopMplsLabelError::NotMpls {
+                ether_type: ether_type as u16,
+            });
+        }
+
+        let headers_len = PAYLOAD_START_NO_VLAN + vlan_tag_len;
+        let label = MplsLabel::from_bytes(cursor.peek_at::<4>(headers_len)?);
+        let ether_type = if label.is_bottom_of_stack() {
+            let [first_byte] = *cursor.peek_at::<1>(headers_len + MPLS_LABEL_SIZE)?;
+            match guess_payload_type(label, first_byte) {
+                MplsPayloadType::Ipv4 => EtherType::IpV4,
+                MplsPayloadType::Ipv6 => EtherType::IpV6,
+                _ => return Err(PopMplsLabelError::UnsupportedPayload { first_byte }),
             }
         } else {
-            Ok(self
-                .headers
-                .get(ETHER_TYPE_START..ETHER_TYPE_END)
-                .ok_or(GetHeaderError::OutOfBoundsBufferAccess)?
-                .try_into()?)
+            ether_type
+        };
+        let buf = cursor.into_inner();
+
+        let moved = buf
+            .get_mut(..MPLS_LABEL_SIZE + headers_len)
+            .ok_or(PopMplsLabelError::OutOfBoundsBufferAccess)?;
+        moved.copy_within(..headers_len, MPLS_LABEL_SIZE);
+        moved
+            .get_mut(MPLS_LABEL_SIZE + headers_len - 2..MPLS_LABEL_SIZE + headers_len)
+            .ok_or(PopMplsLabelError::OutOfBoundsBufferAccess)?
+            .copy_from_slice(&(ether_type as u16).to_be_bytes());
+
+        // The `get_mut` above guarantees that `buf` is longer than the label and `headers_len`.
+        let (_, frame) = buf.split_buffer(MPLS_LABEL_SIZE);
+        let (headers, payload) = frame.split_buffer(headers_len);
+        Ok((
+            EtherFrame {
+                vlan_tag_count: vlan_tag_len / VLAN_TAG_SIZE,
+                ether_type,
+                headers,
+            },
+            payload,
+        ))
+    }
+
+    /// Copies both MAC addresses `offset` bytes towards the payload and drops the first
+    /// `offset` bytes from `headers`.
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn move_macs_forward(&mut self, offset: usize) -> Option<()> {
+        let macs: [u8; 12] = self
+            .headers
+            .get(DESTINATION_MAC_START..SOURCE_MAC_END)?
+            .try_into()
+            .ok()?;
+        self.headers
+            .get_mut(DESTINATION_MAC_START + offset..SOURCE_MAC_END + offset)?
+            .copy_from_slice(&macs);
+
+        // The `get_mut` above guarantees that `headers` is longer than `offset`.
+        let (_, headers) = core::mem::take(&mut self.headers).split_at_mut(offset);
+        self.headers = headers;
+        Some(())
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn set_destination(&mut self, destination: &[u8; 6]) -> Result<(), SetHeaderError> {
+        self.headers
+            .get_mut(DESTINATION_MAC_START..DESTINATION_MAC_END)
+            .ok_or(SetHeaderError::OutOfBoundsBufferAccess)?
+            .copy_from_slice(destination);
+        Ok(())
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn set_source(&mut self, source: &[u8; 6]) -> Result<(), SetHeaderError> {
+        self.headers
+            .get_mut(SOURCE_MAC_START..SOURCE_MAC_END)
+            .ok_or(SetHeaderError::OutOfBoundsBufferAccess)?
+            .copy_from_slice(source);
+        Ok(())
+    }
+
+    /// Writes `ether_type` behind the VLAN tags, if there are any, and updates the cached
+    /// typed ether type.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn set_ether_type(&mut self, ether_type: EtherType) -> Result<(), SetHeaderError> {
+        if ether_type == EtherType::EtherTypeErrorVariant || ether_type == EtherType::Ieee8023Length
+        {
+            return Err(SetHeaderError::InvalidEtherType);
+        }
+
+        let ether_type_start = self.ether_type_start();
+        self.headers
+            .get_mut(ether_type_start..ether_type_start + 2)
+            .ok_or(SetHeaderError::OutOfBoundsBufferAccess)?
+            .copy_from_slice(&(ether_type as u16).to_be_bytes());
+        self.ether_type = ether_type;
+        Ok(())
+    }
+
+    /// Like [`EtherFrame::set_ether_type`], but also accepts values without an [`EtherType`]
+    /// variant and IEEE 802.3 lengths. The cached typed ether type is set to
+    /// [`EtherType::EtherTypeErrorVariant`] or [`EtherType::Ieee8023Length`] for those.
+    ///
+    /// VLAN TPIDs are rejected, they would turn the payload into a VLAN tag. Use
+    /// [`EtherFrame::push_vlan_tag`] to add tags.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn set_raw_ether_type(&mut self, ether_type: u16) -> Result<(), SetHeaderError> {
+        if is_vlan_tpid(ether_type) {
+            return Err(SetHeaderError::VlanTpid { ether_type });
+        }
+
+        let ether_type_start = self.ether_type_start();
+        self.headers
+            .get_mut(ether_type_start..ether_type_start + 2)
+            .ok_or(SetHeaderError::OutOfBoundsBufferAccess)?
+            .copy_from_slice(&ether_type.to_be_bytes());
+        self.ether_type = match Self::lookup_ether_type(ether_type, true) {
+            Ok(ether_type) => ether_type,
+            Err(_) => EtherType::EtherTypeErrorVariant,
+        };
+        Ok(())
+    }
+
+    /// Swaps destination and source MAC address in place, e.g. to send a frame back with
+    /// `XDP_TX`.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn swap_macs(&mut self) -> Result<(), SetHeaderError> {
+        let macs = self
+            .headers
+            .get_mut(DESTINATION_MAC_START..SOURCE_MAC_END)
+            .ok_or(SetHeaderError::OutOfBoundsBufferAccess)?;
+        let destination: [u8; 6] = macs
+            .get(DESTINATION_MAC_START..DESTINATION_MAC_END)
+            .ok_or(SetHeaderError::OutOfBoundsBufferAccess)?
+            .try_into()?;
+        macs.copy_within(SOURCE_MAC_START..SOURCE_MAC_END, DESTINATION_MAC_START);
+        macs.get_mut(SOURCE_MAC_START..SOURCE_MAC_END)
+            .ok_or(SetHeaderError::OutOfBoundsBufferAccess)?
+            .copy_from_slice(&destination);
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "fmt"))]
+mod tests {
+    use super::*;
+
+    const MACS: [u8; 12] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+    const C_TAG: [u8; 4] = [0x81, 0x00, 0x20, 0x0A];
+    const S_TAG: [u8; 4] = [0x88, 0xA8, 0x00, 0x64];
+    const IPV4: [u8; 2] = [0x08, 0x00];
+    const PAYLOAD: [u8; 4] = [0xDE, 0xAD, 0xBE, 0xEF];
+
+    /// Concatenates `parts` into the front of a zeroed buffer, returns the buffer and the
+    /// number of bytes written.
+    fn bytes(parts: &[&[u8]]) -> ([u8; 64], usize) {
+        let mut buf = [0u8; 64];
+        let mut len = 0;
+        for part in parts {
+            buf[len..len + part.len()].copy_from_slice(part);
+            len += part.len();
         }
+        (buf, len)
+    }
+
+    fn parse(buf: &mut [u8]) -> EtherFrame<&mut [u8]> {
+        let end = buf.as_ptr() as usize + buf.len();
+        EtherFrame::new_bpf_auto_vlan(buf, end).unwrap().0
+    }
+
+    #[test]
+    fn cut_first_vlan_tag_single_tagged() {
+        let (mut buf, len) = bytes(&[&MACS, &C_TAG, &IPV4, &PAYLOAD]);
+        let buf = &mut buf[..len];
+
+        let mut frame = parse(buf);
+        assert_eq!(frame.cut_first_vlan_tag(), Ok(VLAN_TAG_SIZE));
+        assert_eq!(frame.get_vlan_tag_count(), 0);
+        assert_eq!(frame.get_typed_ether_type(), EtherType::IpV4);
+        assert_eq!(frame.get_raw_ether_type(), Ok(0x0800));
+
+        let (expected, expected_len) = bytes(&[&MACS, &IPV4, &PAYLOAD]);
+        assert_eq!(&buf[VLAN_TAG_SIZE..], &expected[..expected_len]);
+    }
+
+    #[test]
+    fn cut_first_vlan_tag_double_tagged() {
+        let (mut buf, len) = bytes(&[&MACS, &S_TAG, &C_TAG, &IPV4, &PAYLOAD]);
+        let buf = &mut buf[..len];
+
+        let mut frame = parse(buf);
+        assert_eq!(frame.cut_first_vlan_tag(), Ok(VLAN_TAG_SIZE));
+        assert_eq!(frame.get_vlan_tag_count(), 1);
+        assert_eq!(
+            frame.get_first_vlan_parameter(),
+            Ok(VlanTag::from_bytes(&C_TAG))
+        );
+        assert_eq!(frame.get_raw_ether_type(), Ok(0x0800));
+
+        let (expected, expected_len) = bytes(&[&MACS, &C_TAG, &IPV4, &PAYLOAD]);
+        assert_eq!(&buf[VLAN_TAG_SIZE..], &expected[..expected_len]);
+    }
+
+    #[test]
+    fn cut_first_vlan_tag_untagged() {
+        let (mut buf, len) = bytes(&[&MACS, &IPV4, &PAYLOAD]);
+        let buf = &mut buf[..len];
+
+        let mut frame = parse(buf);
+        assert_eq!(
+            frame.cut_first_vlan_tag(),
+            Err(CutFirstVlanTagError::NotVlanTagged)
+        );
+
+        let (expected, expected_len) = bytes(&[&MACS, &IPV4, &PAYLOAD]);
+        assert_eq!(buf, &expected[..expected_len]);
+    }
+
+    #[test]
+    fn cut_both_vlan_tags_double_tagged() {
+        let (mut buf, len) = bytes(&[&MACS, &S_TAG, &C_TAG, &IPV4, &PAYLOAD]);
+        let buf = &mut buf[..len];
+
+        let mut frame = parse(buf);
+        assert_eq!(frame.cut_both_vlan_tags(), Ok(2 * VLAN_TAG_SIZE));
+        assert_eq!(frame.get_vlan_tag_count(), 0);
+        assert_eq!(frame.get_raw_ether_type(), Ok(0x0800));
+
+        let (expected, expected_len) = bytes(&[&MACS, &IPV4, &PAYLOAD]);
+        assert_eq!(&buf[2 * VLAN_TAG_SIZE..], &expected[..expected_len]);
+    }
+
+    #[test]
+    fn cut_both_vlan_tags_single_tagged() {
+        let (mut buf, len) = bytes(&[&MACS, &C_TAG, &IPV4, &PAYLOAD]);
+        let buf = &mut buf[..len];
+
+        let mut frame = parse(buf);
+        assert_eq!(
+            frame.cut_both_vlan_tags(),
+            Err(CutBothVlanTagsError::NotVlanDoubleTagged)
+        );
+        assert_eq!(frame.get_vlan_tag_count(), 1);
+
+        let (expected, expected_len) = bytes(&[&MACS, &C_TAG, &IPV4, &PAYLOAD]);
+        assert_eq!(buf, &expected[..expected_len]);
+    }
+
+    fn push(buf: &mut [u8], tag: VlanTag) -> Result<EtherFrame<&mut [u8]>, PushVlanTagError> {
+        let end = buf.as_ptr() as usize + buf.len();
+        EtherFrame::push_vlan_tag(buf, end, tag).map(|(frame, _)| frame)
+    }
+
+    #[test]
+    fn push_vlan_tag_untagged() {
+        let (mut buf, len) = bytes(&[&[0; 4], &MACS, &IPV4, &PAYLOAD]);
+        let buf = &mut buf[..len];
+
+        let frame = push(buf, VlanTag::from_bytes(&C_TAG)).unwrap();
+        assert_eq!(frame.get_vlan_tag_count(), 1);
+        assert_eq!(frame.get_typed_ether_type(), EtherType::IpV4);
+        assert_eq!(
+            frame.get_first_vlan_parameter(),
+            Ok(VlanTag::from_bytes(&C_TAG))
+        );
+
+        let (expected, expected_len) = bytes(&[&MACS, &C_TAG, &IPV4, &PAYLOAD]);
+        assert_eq!(buf, &expected[..expected_len]);
+    }
+
+    #[test]
+    fn push_vlan_tag_single_tagged() {
+        let (mut buf, len) = bytes(&[&[0; 4], &MACS, &C_TAG, &IPV4, &PAYLOAD]);
+        let buf = &mut buf[..len];
+
+        let frame = push(buf, VlanTag::from_bytes(&S_TAG)).unwrap();
+        assert_eq!(frame.get_vlan_tag_count(), 2);
+        assert_eq!(frame.get_typed_ether_type(), EtherType::IpV4);
+        assert_eq!(
+            frame.get_second_vlan_parameter(),
+            Ok(VlanTag::from_bytes(&C_TAG))
+        );
+
+        let (expected, expected_len) = bytes(&[&MACS, &S_TAG, &C_TAG, &IPV4, &PAYLOAD]);
+        assert_eq!(buf, &expected[..expected_len]);
+    }
+
+    #[test]
+    fn push_vlan_tag_unknown_ether_type() {
+        let (mut buf, len) = bytes(&[&[0; 4], &MACS, &[0x12, 0x34], &PAYLOAD]);
+        let buf = &mut buf[..len];
+
+        let frame = push(buf, VlanTag::from_bytes(&C_TAG)).unwrap();
+        assert_eq!(
+            frame.get_typed_ether_type(),
+            EtherType::EtherTypeErrorVariant
+        );
+        assert_eq!(frame.get_raw_ether_type(), Ok(0x1234));
+
+        let (expected, expected_len) = bytes(&[&MACS, &C_TAG, &[0x12, 0x34], &PAYLOAD]);
+        assert_eq!(buf, &expected[..expected_len]);
+    }
+
+    #[test]
+    fn push_vlan_tag_without_headroom() {
+        let (mut buf, len) = bytes(&[&MACS, &IPV4]);
+        let buf = &mut buf[..len];
+
+        assert_eq!(
+            push(buf, VlanTag::from_bytes(&C_TAG)),
+            Err(PushVlanTagError::BpfOutOfBounds)
+        );
+
+        let (expected, expected_len) = bytes(&[&MACS, &IPV4]);
+        assert_eq!(buf, &expected[..expected_len]);
+    }
+
+    #[test]
+    fn push_vlan_tag_too_many_tags() {
+        let (mut buf, len) = bytes(&[&[0; 4], &MACS, &S_TAG, &C_TAG, &C_TAG, &IPV4]);
+        let buf = &mut buf[..len];
+
+        assert_eq!(
+            push(buf, VlanTag::from_bytes(&S_TAG)),
+            Err(PushVlanTagError::TooManyVlanTags)
+        );
+    }
+
+    #[test]
+    fn push_vlan_tag_unsupported_tpid() {
+        let (mut buf, len) = bytes(&[&[0; 4], &MACS, &IPV4]);
+        let buf = &mut buf[..len];
+
+        assert_eq!(
+            push(buf, VlanTag::new(0x0800, 0, false, 1)),
+            Err(PushVlanTagError::UnsupportedTpid { tpid: 0x0800 })
+        );
+    }
+
+    const MPLS: [u8; 2] = [0x88, 0x47];
+    const IPV4_PAYLOAD: [u8; 4] = [0x45, 0x00, 0x00, 0x14];
+    // Label 100, TTL 64, with and without the bottom of stack bit.
+    const LABEL_100_BOTTOM: [u8; 4] = [0x00, 0x06, 0x41, 0x40];
+    const LABEL_100: [u8; 4] = [0x00, 0x06, 0x40, 0x40];
+    // Label 200, TTL 64, without the bottom of stack bit.
+    const LABEL_200: [u8; 4] = [0x00, 0x0C, 0x80, 0x40];
+
+    fn push_mpls(
+        buf: &mut [u8],
+        label: MplsLabel,
+    ) -> Result<EtherFrame<&mut [u8]>, PushMplsLabelError> {
+        let end = buf.as_ptr() as usize + buf.len();
+        EtherFrame::push_mpls_label(buf, end, label).map(|(frame, _)| frame)
+    }
+
+    fn pop_mpls(buf: &mut [u8]) -> Result<EtherFrame<&mut [u8]>, PopMplsLabelError> {
+        let end = buf.as_ptr() as usize + buf.len();
+        EtherFrame::pop_mpls_label(buf, end).map(|(frame, _)| frame)
+    }
+
+    #[test]
+    fn push_and_pop_mpls_label_round_trip() {
+        let (mut buf, len) = bytes(&[&[0; 4], &MACS, &C_TAG, &IPV4, &IPV4_PAYLOAD]);
+        let buf = &mut buf[..len];
+
+        // The bottom of stack bit is set for the first label, whatever `label` says.
+        let frame = push_mpls(buf, MplsLabel::from_bytes(&LABEL_100)).unwrap();
+        assert_eq!(frame.get_typed_ether_type(), EtherType::MplsUnicast);
+        assert_eq!(frame.get_vlan_tag_count(), 1);
+        let (expected, expected_len) =
+            bytes(&[&MACS, &C_TAG, &MPLS, &LABEL_100_BOTTOM, &IPV4_PAYLOAD]);
+        assert_eq!(&*buf, &expected[..expected_len]);
+
+        let frame = pop_mpls(buf).unwrap();
+        assert_eq!(frame.get_typed_ether_type(), EtherType::IpV4);
+        assert_eq!(frame.get_vlan_tag_count(), 1);
+        let (expected, expected_len) = bytes(&[&MACS, &C_TAG, &IPV4, &IPV4_PAYLOAD]);
+        assert_eq!(&buf[MPLS_LABEL_SIZE..], &expected[..expected_len]);
+    }
+
+    #[test]
+    fn push_and_pop_mpls_label_on_existing_stack() {
+        let (mut buf, len) = bytes(&[&[0; 4], &MACS, &MPLS, &LABEL_100_BOTTOM, &IPV4_PAYLOAD]);
+        let buf = &mut buf[..len];
+
+        // A label pushed on top of an existing stack is never the bottom of the stack.
+        let label = MplsLabel::from_bytes(&LABEL_200).with_bottom_of_stack(true);
+        let frame = push_mpls(buf, label).unwrap();
+        assert_eq!(frame.get_typed_ether_type(), EtherType::MplsUnicast);
+        let (expected, expected_len) =
+            bytes(&[&MACS, &MPLS, &LABEL_200, &LABEL_100_BOTTOM, &IPV4_PAYLOAD]);
+        assert_eq!(&*buf, &expected[..expected_len]);
+
+        // Popping a label above the bottom of the stack keeps the MPLS ether type.
+        let frame = pop_mpls(buf).unwrap();
+        assert_eq!(frame.get_typed_ether_type(), EtherType::MplsUnicast);
+        let (expected, expected_len) = bytes(&[&MACS, &MPLS, &LABEL_100_BOTTOM, &IPV4_PAYLOAD]);
+        assert_eq!(&buf[MPLS_LABEL_SIZE..], &expected[..expected_len]);
+    }
+
+    #[test]
+    fn pop_mpls_label_not_mpls() {
+        let (mut buf, len) = bytes(&[&MACS, &IPV4, &IPV4_PAYLOAD]);
+        let buf = &mut buf[..len];
+
+        assert_eq!(
+            pop_mpls(buf),
+            Err(PopMplsLabelError::NotMpls { ether_type: 0x0800 })
+        );
+    }
+
+    #[test]
+    fn set_raw_ether_type() {
+        let (mut buf, len) = bytes(&[&MACS, &C_TAG, &IPV4, &PAYLOAD]);
+        let buf = &mut buf[..len];
+
+        let mut frame = parse(buf);
+        assert_eq!(frame.set_raw_ether_type(0x86DD), Ok(()));
+        assert_eq!(frame.get_typed_ether_type(), EtherType::IpV6);
+        assert_eq!(frame.set_raw_ether_type(0x1234), Ok(()));
+        assert_eq!(
+            frame.get_typed_ether_type(),
+            EtherType::EtherTypeErrorVariant
+        );
+        assert_eq!(frame.set_raw_ether_type(46), Ok(()));
+        assert_eq!(frame.get_typed_ether_type(), EtherType::Ieee8023Length);
+
+        let (expected, expected_len) = bytes(&[&MACS, &C_TAG, &[0x00, 46], &PAYLOAD]);
+        assert_eq!(buf, &expected[..expected_len]);
+    }
+
+    #[test]
+    fn set_raw_ether_type_rejects_vlan_tpid() {
+        let (mut buf, len) = bytes(&[&MACS, &IPV4, &PAYLOAD]);
+        let buf = &mut buf[..len];
+
+        let mut frame = parse(buf);
+        assert_eq!(
+            frame.set_raw_ether_type(0x88A8),
+            Err(SetHeaderError::VlanTpid { ether_type: 0x88A8 })
+        );
+        assert_eq!(frame.get_typed_ether_type(), EtherType::IpV4);
+
+        let (expected, expected_len) = bytes(&[&MACS, &IPV4, &PAYLOAD]);
+        assert_eq!(buf, &expected[..expected_len]);
     }
 }
@@ -1,4 +1,7 @@
+use crate::cursor::CursorError;
 use crate::ethernet::header_values::EtherTypeParsingError;
+#[cfg(feature = "fmt")]
+use crate::ethernet::MAX_VLAN_TAGS;
 use core::array::TryFromSliceError;
 #[cfg(feature = "fmt")]
 use core::fmt::{Debug, Display, Formatter};
@@ -8,12 +11,21 @@ use core::fmt::{Debug, Display, Formatter};
 pub enum EthernetError {
     BpfCreation(EthernetBpfCreationError),
     BpfWithVlanCreation(EthernetBpfWithVlanCreationError),
+    BpfAutoVlanCreation(EthernetBpfAutoVlanCreationError),
     MatchEtherWithVlan(MatchEtherWithVlanError),
     GetHeader(GetHeaderError),
     GetFirstVlanParameter(GetFirstVlanParameterError),
     GetSecondVlanParameter(GetSecondVlanParameterError),
+    GetVlanTag(GetVlanTagError),
     CutFirstVlanTag(CutFirstVlanTagError),
     CutBothVlanTags(CutBothVlanTagsError),
+    PushVlanTag(PushVlanTagError),
+    PushMplsLabel(PushMplsLabelError),
+    PopMplsLabel(PopMplsLabelError),
+    SetHeader(SetHeaderError),
+    GetLength(GetLengthError),
+    LlcBpfCreation(LlcBpfCreationError),
+    SnapBpfCreation(SnapBpfCreationError),
 }
 
 #[cfg(feature = "fmt")]
@@ -26,6 +38,9 @@ impl Display for EthernetError {
             EthernetError::BpfWithVlanCreation(err) => {
                 write!(f, "{err}")
             }
+            EthernetError::BpfAutoVlanCreation(err) => {
+                write!(f, "{err}")
+            }
             EthernetError::MatchEtherWithVlan(err) => {
                 write!(f, "{err}")
             }
@@ -38,12 +53,36 @@ impl Display for EthernetError {
             EthernetError::GetSecondVlanParameter(err) => {
                 write!(f, "{err}")
             }
+            EthernetError::GetVlanTag(err) => {
+                write!(f, "{err}")
+            }
             EthernetError::CutFirstVlanTag(err) => {
                 write!(f, "{err}")
             }
             EthernetError::CutBothVlanTags(err) => {
                 write!(f, "{err}")
             }
+            EthernetError::PushVlanTag(err) => {
+                write!(f, "{err}")
+            }
+            EthernetError::PushMplsLabel(err) => {
+                write!(f, "{err}")
+            }
+            EthernetError::PopMplsLabel(err) => {
+                write!(f, "{err}")
+            }
+            EthernetError::SetHeader(err) => {
+                write!(f, "{err}")
+            }
+            EthernetError::GetLength(err) => {
+                write!(f, "{err}")
+            }
+            EthernetError::LlcBpfCreation(err) => {
+                write!(f, "{err}")
+            }
+            EthernetError::SnapBpfCreation(err) => {
+                write!(f, "{err}")
+            }
         }
     }
 }
@@ -60,6 +99,12 @@ impl From<EthernetBpfWithVlanCreationError> for EthernetError {
     }
 }
 
+impl From<EthernetBpfAutoVlanCreationError> for EthernetError {
+    fn from(value: EthernetBpfAutoVlanCreationError) -> Self {
+        Self::BpfAutoVlanCreation(value)
+    }
+}
+
 impl From<MatchEtherWithVlanError> for EthernetError {
     fn from(value: MatchEtherWithVlanError) -> Self {
         Self::MatchEtherWithVlan(value)
@@ -84,6 +129,12 @@ impl From<GetSecondVlanParameterError> for EthernetError {
     }
 }
 
+impl From<GetVlanTagError> for EthernetError {
+    fn from(value: GetVlanTagError) -> Self {
+        Self::GetVlanTag(value)
+    }
+}
+
 impl From<CutFirstVlanTagError> for EthernetError {
     fn from(value: CutFirstVlanTagError) -> Self {
         Self::CutFirstVlanTag(value)
@@ -96,6 +147,48 @@ impl From<CutBothVlanTagsError> for EthernetError {
     }
 }
 
+impl From<PushVlanTagError> for EthernetError {
+    fn from(value: PushVlanTagError) -> Self {
+        Self::PushVlanTag(value)
+    }
+}
+
+impl From<PushMplsLabelError> for EthernetError {
+    fn from(value: PushMplsLabelError) -> Self {
+        Self::PushMplsLabel(value)
+    }
+}
+
+impl From<PopMplsLabelError> for EthernetError {
+    fn from(value: PopMplsLabelError) -> Self {
+        Self::PopMplsLabel(value)
+    }
+}
+
+impl From<SetHeaderError> for EthernetError {
+    fn from(value: SetHeaderError) -> Self {
+        Self::SetHeader(value)
+    }
+}
+
+impl From<GetLengthError> for EthernetError {
+    fn from(value: GetLengthError) -> Self {
+        Self::GetLength(value)
+    }
+}
+
+impl From<LlcBpfCreationError> for EthernetError {
+    fn from(value: LlcBpfCreationError) -> Self {
+        Self::LlcBpfCreation(value)
+    }
+}
+
+impl From<SnapBpfCreationError> for EthernetError {
+    fn from(value: SnapBpfCreationError) -> Self {
+        Self::SnapBpfCreation(value)
+    }
+}
+
 #[cfg(feature = "error_trait")]
 impl core::error::Error for EthernetError {}
 
@@ -125,6 +218,17 @@ impl From<TryFromSliceError> for EthernetBpfCreationError {
     }
 }
 
+impl From<CursorError> for EthernetBpfCreationError {
+    fn from(value: CursorError) -> Self {
+        match value {
+            CursorError::TooShort { size, .. } => Self::FrameTooShort { size },
+            CursorError::BpfOutOfBounds => Self::BpfOutOfBounds,
+            CursorError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            CursorError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
 #[cfg(feature = "fmt")]
 impl Display for EthernetBpfCreationError {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
@@ -158,6 +262,7 @@ impl core::error::Error for EthernetBpfCreationError {}
 #[derive(Copy, Clone, Eq, PartialEq)]
 pub enum EthernetBpfWithVlanCreationError {
     NoRecognizedEtherType { ether_type: u16 },
+    TooManyVlanTags,
     FrameTooShort { size: usize },
     BpfOutOfBounds,
     OutOfBoundsBufferAccess,
@@ -170,12 +275,26 @@ impl From<TryFromSliceError> for EthernetBpfWithVlanCreationError {
     }
 }
 
+impl From<CursorError> for EthernetBpfWithVlanCreationError {
+    fn from(value: CursorError) -> Self {
+        match value {
+            CursorError::TooShort { size, .. } => Self::FrameTooShort { size },
+            CursorError::BpfOutOfBounds => Self::BpfOutOfBounds,
+            CursorError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            CursorError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
 impl From<MatchEtherWithVlanError> for EthernetBpfWithVlanCreationError {
     fn from(value: MatchEtherWithVlanError) -> Self {
         match value {
             MatchEtherWithVlanError::NoRecognizedEtherType { ether_type } => {
                 Self::NoRecognizedEtherType { ether_type }
             }
+            MatchEtherWithVlanError::TooManyVlanTags => Self::TooManyVlanTags,
+            MatchEtherWithVlanError::FrameTooShort { size, .. } => Self::FrameTooShort { size },
+            MatchEtherWithVlanError::BpfOutOfBounds => Self::BpfOutOfBounds,
             MatchEtherWithVlanError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
             MatchEtherWithVlanError::CouldNotConvertSliceToArray => {
                 Self::CouldNotConvertSliceToArray
@@ -191,6 +310,9 @@ impl Display for EthernetBpfWithVlanCreationError {
             Self::NoRecognizedEtherType { ether_type } => {
                 write!(f, "No valid ether type, was: {ether_type:#06X}")
             }
+            Self::TooManyVlanTags => {
+                write!(f, "Frame has more than {MAX_VLAN_TAGS} VLAN tags")
+            }
             Self::FrameTooShort { size } => {
                 write!(
                     f,
@@ -213,10 +335,102 @@ impl Display for EthernetBpfWithVlanCreationError {
 #[cfg(feature = "error_trait")]
 impl core::error::Error for EthernetBpfWithVlanCreationError {}
 
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum EthernetBpfAutoVlanCreationError {
+    NoRecognizedEtherType { ether_type: u16 },
+    TooManyVlanTags,
+    FrameTooShort { size: usize, expected: usize },
+    BpfOutOfBounds,
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<EtherTypeParsingError> for EthernetBpfAutoVlanCreationError {
+    fn from(value: EtherTypeParsingError) -> Self {
+        match value {
+            EtherTypeParsingError::NoRecognizedEtherType { ether_type } => {
+                Self::NoRecognizedEtherType { ether_type }
+            }
+        }
+    }
+}
+
+impl From<MatchEtherWithVlanError> for EthernetBpfAutoVlanCreationError {
+    fn from(value: MatchEtherWithVlanError) -> Self {
+        match value {
+            MatchEtherWithVlanError::NoRecognizedEtherType { ether_type } => {
+                Self::NoRecognizedEtherType { ether_type }
+            }
+            MatchEtherWithVlanError::TooManyVlanTags => Self::TooManyVlanTags,
+            MatchEtherWithVlanError::FrameTooShort { size, expected } => {
+                Self::FrameTooShort { size, expected }
+            }
+            MatchEtherWithVlanError::BpfOutOfBounds => Self::BpfOutOfBounds,
+            MatchEtherWithVlanError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            MatchEtherWithVlanError::CouldNotConvertSliceToArray => {
+                Self::CouldNotConvertSliceToArray
+            }
+        }
+    }
+}
+
+impl From<TryFromSliceError> for EthernetBpfAutoVlanCreationError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+impl From<CursorError> for EthernetBpfAutoVlanCreationError {
+    fn from(value: CursorError) -> Self {
+        match value {
+            CursorError::TooShort { size, expected } => Self::FrameTooShort { size, expected },
+            CursorError::BpfOutOfBounds => Self::BpfOutOfBounds,
+            CursorError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            CursorError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for EthernetBpfAutoVlanCreationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NoRecognizedEtherType { ether_type } => {
+                write!(f, "No valid ether type, was: {ether_type:#06X}")
+            }
+            Self::TooManyVlanTags => {
+                write!(f, "Frame has more than {MAX_VLAN_TAGS} VLAN tags")
+            }
+            Self::FrameTooShort { size, expected } => {
+                write!(
+                    f,
+                    "Ethernet frame expected to be at least {expected} bytes, was: {size}"
+                )
+            }
+            Self::BpfOutOfBounds => {
+                write!(f, "Bpf out of bounds")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for EthernetBpfAutoVlanCreationError {}
+
 #[cfg_attr(feature = "fmt", derive(Debug))]
 #[derive(Copy, Clone, Eq, PartialEq)]
 pub enum MatchEtherWithVlanError {
     NoRecognizedEtherType { ether_type: u16 },
+    TooManyVlanTags,
+    FrameTooShort { size: usize, expected: usize },
+    BpfOutOfBounds,
     OutOfBoundsBufferAccess,
     CouldNotConvertSliceToArray,
 }
@@ -237,6 +451,17 @@ impl From<TryFromSliceError> for MatchEtherWithVlanError {
     }
 }
 
+impl From<CursorError> for MatchEtherWithVlanError {
+    fn from(value: CursorError) -> Self {
+        match value {
+            CursorError::TooShort { size, expected } => Self::FrameTooShort { size, expected },
+            CursorError::BpfOutOfBounds => Self::BpfOutOfBounds,
+            CursorError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            CursorError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
 #[cfg(feature = "fmt")]
 impl Display for MatchEtherWithVlanError {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
@@ -244,6 +469,18 @@ impl Display for MatchEtherWithVlanError {
             Self::NoRecognizedEtherType { ether_type } => {
                 write!(f, "No valid ether type, was: {ether_type:#06X}")
             }
+            Self::TooManyVlanTags => {
+                write!(f, "Frame has more than {MAX_VLAN_TAGS} VLAN tags")
+            }
+            Self::FrameTooShort { size, expected } => {
+                write!(
+                    f,
+                    "Ethernet frame expected to be at least {expected} bytes, was: {size}"
+                )
+            }
+            Self::BpfOutOfBounds => {
+                write!(f, "Bpf out of bounds")
+            }
             Self::OutOfBoundsBufferAccess => {
                 write!(f, "Out of bound access, .get(...) returned None")
             }
@@ -287,6 +524,50 @@ impl Display for GetHeaderError {
 #[cfg(feature = "error_trait")]
 impl core::error::Error for GetHeaderError {}
 
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum SetHeaderError {
+    InvalidEtherType,
+    VlanTpid { ether_type: u16 },
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for SetHeaderError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for SetHeaderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidEtherType => {
+                write!(
+                    f,
+                    "Error and IEEE 802.3 length variants can't be written as ether type"
+                )
+            }
+            Self::VlanTpid { ether_type } => {
+                write!(
+                    f,
+                    "VLAN TPID can't be written as ether type, was: {ether_type:#06X}"
+                )
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for SetHeaderError {}
+
 #[cfg_attr(feature = "fmt", derive(Debug))]
 #[derive(Copy, Clone, Eq, PartialEq)]
 pub enum GetFirstVlanParameterError {
@@ -359,10 +640,48 @@ impl Display for GetSecondVlanParameterError {
 #[cfg(feature = "error_trait")]
 impl core::error::Error for GetSecondVlanParameterError {}
 
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum GetVlanTagError {
+    NoSuchVlanTag { index: usize, tag_count: usize },
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for GetVlanTagError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for GetVlanTagError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NoSuchVlanTag { index, tag_count } => {
+                write!(
+                    f,
+                    "No VLAN tag at index {index}, frame has {tag_count} VLAN tags"
+                )
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for GetVlanTagError {}
+
 #[cfg_attr(feature = "fmt", derive(Debug))]
 #[derive(Copy, Clone, Eq, PartialEq)]
 pub enum CutFirstVlanTagError {
     NotVlanTagged,
+    OutOfBoundsBufferAccess,
     CouldNotConvertSliceToArray,
 }
 
@@ -379,6 +698,9 @@ impl Display for CutFirstVlanTagError {
             Self::NotVlanTagged => {
                 write!(f, "Frame is not VLAN tagged")
             }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
             Self::CouldNotConvertSliceToArray => {
                 write!(f, "Could not convert slice to array")
             }
@@ -394,6 +716,7 @@ impl core::error::Error for CutFirstVlanTagError {}
 pub enum CutBothVlanTagsError {
     NotVlanTagged,
     NotVlanDoubleTagged,
+    OutOfBoundsBufferAccess,
     CouldNotConvertSliceToArray,
 }
 
@@ -413,6 +736,9 @@ impl Display for CutBothVlanTagsError {
             Self::NotVlanDoubleTagged => {
                 write!(f, "Frame is not double VLAN tagged")
             }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
             Self::CouldNotConvertSliceToArray => {
                 write!(f, "Could not convert slice to array")
             }
@@ -422,3 +748,407 @@ impl Display for CutBothVlanTagsError {
 
 #[cfg(feature = "error_trait")]
 impl core::error::Error for CutBothVlanTagsError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum PushVlanTagError {
+    NoRecognizedEtherType { ether_type: u16 },
+    UnsupportedTpid { tpid: u16 },
+    TooManyVlanTags,
+    FrameTooShort { size: usize, expected: usize },
+    BpfOutOfBounds,
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<EtherTypeParsingError> for PushVlanTagError {
+    fn from(value: EtherTypeParsingError) -> Self {
+        match value {
+            EtherTypeParsingError::NoRecognizedEtherType { ether_type } => {
+                Self::NoRecognizedEtherType { ether_type }
+            }
+        }
+    }
+}
+
+impl From<MatchEtherWithVlanError> for PushVlanTagError {
+    fn from(value: MatchEtherWithVlanError) -> Self {
+        match value {
+            MatchEtherWithVlanError::NoRecognizedEtherType { ether_type } => {
+                Self::NoRecognizedEtherType { ether_type }
+            }
+            MatchEtherWithVlanError::TooManyVlanTags => Self::TooManyVlanTags,
+            MatchEtherWithVlanError::FrameTooShort { size, expected } => {
+                Self::FrameTooShort { size, expected }
+            }
+            MatchEtherWithVlanError::BpfOutOfBounds => Self::BpfOutOfBounds,
+            MatchEtherWithVlanError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            MatchEtherWithVlanError::CouldNotConvertSliceToArray => {
+                Self::CouldNotConvertSliceToArray
+            }
+        }
+    }
+}
+
+impl From<TryFromSliceError> for PushVlanTagError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+impl From<CursorError> for PushVlanTagError {
+    fn from(value: CursorError) -> Self {
+        match value {
+            CursorError::TooShort { size, expected } => Self::FrameTooShort { size, expected },
+            CursorError::BpfOutOfBounds => Self::BpfOutOfBounds,
+            CursorError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            CursorError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for PushVlanTagError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NoRecognizedEtherType { ether_type } => {
+                write!(f, "No valid ether type, was: {ether_type:#06X}")
+            }
+            Self::UnsupportedTpid { tpid } => {
+                write!(f, "No valid VLAN TPID, was: {tpid:#06X}")
+            }
+            Self::TooManyVlanTags => {
+                write!(f, "Frame would have more than {MAX_VLAN_TAGS} VLAN tags")
+            }
+            Self::FrameTooShort { size, expected } => {
+                write!(
+                    f,
+                    "Ethernet frame with headroom expected to be at least {expected} bytes, was: {size}"
+                )
+            }
+            Self::BpfOutOfBounds => {
+                write!(f, "Bpf out of bounds")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for PushVlanTagError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum PushMplsLabelError {
+    NoRecognizedEtherType { ether_type: u16 },
+    Ieee8023Frame,
+    TooManyVlanTags,
+    FrameTooShort { size: usize, expected: usize },
+    BpfOutOfBounds,
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<MatchEtherWithVlanError> for PushMplsLabelError {
+    fn from(value: MatchEtherWithVlanError) -> Self {
+        match value {
+            MatchEtherWithVlanError::NoRecognizedEtherType { ether_type } => {
+                Self::NoRecognizedEtherType { ether_type }
+            }
+            MatchEtherWithVlanError::TooManyVlanTags => Self::TooManyVlanTags,
+            MatchEtherWithVlanError::FrameTooShort { size, expected } => {
+                Self::FrameTooShort { size, expected }
+            }
+            MatchEtherWithVlanError::BpfOutOfBounds => Self::BpfOutOfBounds,
+            MatchEtherWithVlanError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            MatchEtherWithVlanError::CouldNotConvertSliceToArray => {
+                Self::CouldNotConvertSliceToArray
+            }
+        }
+    }
+}
+
+impl From<TryFromSliceError> for PushMplsLabelError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+impl From<CursorError> for PushMplsLabelError {
+    fn from(value: CursorError) -> Self {
+        match value {
+            CursorError::TooShort { size, expected } => Self::FrameTooShort { size, expected },
+            CursorError::BpfOutOfBounds => Self::BpfOutOfBounds,
+            CursorError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            CursorError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for PushMplsLabelError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NoRecognizedEtherType { ether_type } => {
+                write!(f, "No valid ether type, was: {ether_type:#06X}")
+            }
+            Self::Ieee8023Frame => {
+                write!(f, "Can't push an MPLS label onto an IEEE 802.3 frame")
+            }
+            Self::TooManyVlanTags => {
+                write!(f, "Frame has more than {MAX_VLAN_TAGS} VLAN tags")
+            }
+            Self::FrameTooShort { size, expected } => {
+                write!(
+                    f,
+                    "Ethernet frame with headroom expected to be at least {expected} bytes, was: {size}"
+                )
+            }
+            Self::BpfOutOfBounds => {
+                write!(f, "Bpf out of bounds")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for PushMplsLabelError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum PopMplsLabelError {
+    NoRecognizedEtherType { ether_type: u16 },
+    NotMpls { ether_type: u16 },
+    UnsupportedPayload { first_byte: u8 },
+    TooManyVlanTags,
+    FrameTooShort { size: usize, expected: usize },
+    BpfOutOfBounds,
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<MatchEtherWithVlanError> for PopMplsLabelError {
+    fn from(value: MatchEtherWithVlanError) -> Self {
+        match value {
+            MatchEtherWithVlanError::NoRecognizedEtherType { ether_type } => {
+                Self::NoRecognizedEtherType { ether_type }
+            }
+            MatchEtherWithVlanError::TooManyVlanTags => Self::TooManyVlanTags,
+            MatchEtherWithVlanError::FrameTooShort { size, expected } => {
+                Self::FrameTooShort { size, expected }
+            }
+            MatchEtherWithVlanError::BpfOutOfBounds => Self::BpfOutOfBounds,
+            MatchEtherWithVlanError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            MatchEtherWithVlanError::CouldNotConvertSliceToArray => {
+                Self::CouldNotConvertSliceToArray
+            }
+        }
+    }
+}
+
+impl From<TryFromSliceError> for PopMplsLabelError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+impl From<CursorError> for PopMplsLabelError {
+    fn from(value: CursorError) -> Self {
+        match value {
+            CursorError::TooShort { size, expected } => Self::FrameTooShort { size, expected },
+            CursorError::BpfOutOfBounds => Self::BpfOutOfBounds,
+            CursorError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            CursorError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for PopMplsLabelError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NoRecognizedEtherType { ether_type } => {
+                write!(f, "No valid ether type, was: {ether_type:#06X}")
+            }
+            Self::NotMpls { ether_type } => {
+                write!(f, "Ether type expected to be MPLS, was: {ether_type:#06X}")
+            }
+            Self::UnsupportedPayload { first_byte } => {
+                write!(
+                    f,
+                    "MPLS payload expected to be IPv4 or IPv6, first byte was: {first_byte:#04X}"
+                )
+            }
+            Self::TooManyVlanTags => {
+                write!(f, "Frame has more than {MAX_VLAN_TAGS} VLAN tags")
+            }
+            Self::FrameTooShort { size, expected } => {
+                write!(
+                    f,
+                    "MPLS frame expected to be at least {expected} bytes, was: {size}"
+                )
+            }
+            Self::BpfOutOfBounds => {
+                write!(f, "Bpf out of bounds")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for PopMplsLabelError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum LlcBpfCreationError {
+    FrameTooShort { size: usize },
+    BpfOutOfBounds,
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for LlcBpfCreationError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+impl From<CursorError> for LlcBpfCreationError {
+    fn from(value: CursorError) -> Self {
+        match value {
+            CursorError::TooShort { size, .. } => Self::FrameTooShort { size },
+            CursorError::BpfOutOfBounds => Self::BpfOutOfBounds,
+            CursorError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            CursorError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for LlcBpfCreationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::FrameTooShort { size } => {
+                write!(f, "LLC header expected to be at least 3 bytes, was: {size}")
+            }
+            Self::BpfOutOfBounds => {
+                write!(f, "Bpf out of bounds")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for LlcBpfCreationError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum SnapBpfCreationError {
+    FrameTooShort { size: usize },
+    BpfOutOfBounds,
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for SnapBpfCreationError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+impl From<CursorError> for SnapBpfCreationError {
+    fn from(value: CursorError) -> Self {
+        match value {
+            CursorError::TooShort { size, .. } => Self::FrameTooShort { size },
+            CursorError::BpfOutOfBounds => Self::BpfOutOfBounds,
+            CursorError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            CursorError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for SnapBpfCreationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::FrameTooShort { size } => {
+                write!(
+                    f,
+                    "SNAP header expected to be at least 5 bytes, was: {size}"
+                )
+            }
+            Self::BpfOutOfBounds => {
+                write!(f, "Bpf out of bounds")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for SnapBpfCreationError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum GetLengthError {
+    NotIeee8023Frame { ether_type: u16 },
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for GetLengthError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for GetLengthError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NotIeee8023Frame { ether_type } => {
+                write!(
+                    f,
+                    "Frame is not an IEEE 802.3 frame, ether type was: {ether_type:#06X}"
+                )
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for GetLengthError {}
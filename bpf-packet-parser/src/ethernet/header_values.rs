@@ -16,6 +16,10 @@ crate::macros::generate_matching_enum_impl! {
         Avtp= 0x22F0,
         /// Stream Reservation Protocol
         Srp = 0x22EA,
+        /// ERSPAN type III, used as GRE protocol type
+        ErspanType3 = 0x22EB,
+        /// Transparent Ethernet Bridging, an Ethernet frame as payload of a tunnel
+        TransparentEthernetBridging = 0x6558,
         /// Reverse Address Resolution Protocol
         Rarp = 0x8035,
         AppleTalk = 0x809B,
@@ -50,6 +54,8 @@ crate::macros::generate_matching_enum_impl! {
         GseManagementServices = 0x88B9,
         /// Sampled Value
         Sv = 0x88BA,
+        /// ERSPAN type I and II, used as GRE protocol type
+        ErspanType2 = 0x88BE,
         /// Link Layer Discovery Protocol
         Lldp = 0x88CC,
         Sercos3 = 0x88CD,
@@ -80,5 +86,12 @@ crate::macros::generate_matching_enum_impl! {
         RTag = 0xF1C1,
     }
     (error_value: 0xF1C0),
-    (lookup_table: false)
+    (lookup_table: false),
+    (unmatched: {
+        /// Not an ether type, values up to 1500 are the payload length of an IEEE 802.3 frame
+        /// which carries an IEEE 802.2 LLC header. Frames report this variant for all length
+        /// values, see `EtherFrame::get_length`. `EtherType::lookup` never returns it, length
+        /// values fail there like unrecognized ether types.
+        Ieee8023Length = 0x0000,
+    })
 }
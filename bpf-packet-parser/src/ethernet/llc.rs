@@ -0,0 +1,149 @@
+use crate::buffer::PacketBuffer;
+use crate::cursor::PacketCursor;
+use crate::ethernet::{GetHeaderError, LlcBpfCreationError, SnapBpfCreationError};
+#[cfg(feature = "fmt")]
+use core::fmt::Debug;
+
+/// Values up to 1500 in the ether type position are the IEEE 802.3 payload length.
+pub static IEEE_802_3_MAX_LENGTH: u16 = 1500;
+
+// All ranges are [start..end).
+// This means start is included, end is not included.
+pub static LLC_DSAP: usize = 0;
+pub static LLC_SSAP: usize = 1;
+pub static LLC_CONTROL_START: usize = 2;
+/// U-format PDUs have a 1 byte control field, I- and S-format PDUs a 2 byte one.
+pub static LLC_U_FORMAT_HEADER_SIZE: usize = 3;
+pub static LLC_I_S_FORMAT_HEADER_SIZE: usize = 4;
+/// DSAP and SSAP announcing a SNAP header behind the LLC header.
+pub static LLC_SAP_SNAP: u8 = 0xAA;
+pub static SNAP_OUI_START: usize = 0;
+pub static SNAP_OUI_END: usize = 3;
+pub static SNAP_PROTOCOL_ID_START: usize = 3;
+pub static SNAP_PROTOCOL_ID_END: usize = 5;
+pub static SNAP_HEADER_SIZE: usize = 5;
+
+/// IEEE 802.2 LLC header, found in the payload of frames with
+/// [`EtherType::Ieee8023Length`](crate::ethernet::EtherType::Ieee8023Length).
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(PartialEq, Eq)]
+pub struct LlcHeader<B> {
+    // size of 3B or 4B
+    header: B,
+}
+
+impl<B: PacketBuffer> LlcHeader<B> {
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn new_bpf(buf: B, end: usize) -> Result<(Self, B), LlcBpfCreationError> {
+        let mut cursor = PacketCursor::new(buf, end);
+        let header = Self::from_cursor(&mut cursor)?;
+        Ok((header, cursor.into_inner()))
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_cursor(cursor: &mut PacketCursor<B>) -> Result<Self, LlcBpfCreationError> {
+        // We read at most the first 4 bytes of the payload:
+        // 1 byte DSAP
+        // 1 byte SSAP
+        // 1-2 bytes control
+        // ------------------
+        // 4 bytes total
+
+        let control = *cursor
+            .peek::<3>()?
+            .get(LLC_CONTROL_START)
+            .ok_or(LlcBpfCreationError::OutOfBoundsBufferAccess)?;
+        let header_len = if control & 0x03 == 0x03 {
+            LLC_U_FORMAT_HEADER_SIZE
+        } else {
+            LLC_I_S_FORMAT_HEADER_SIZE
+        };
+
+        let header = cursor.split_header_len(header_len)?;
+
+        Ok(LlcHeader { header })
+    }
+
+    /// Destination service access point.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_dsap(&self) -> Result<u8, GetHeaderError> {
+        self.header
+            .get(LLC_DSAP)
+            .copied()
+            .ok_or(GetHeaderError::OutOfBoundsBufferAccess)
+    }
+
+    /// Source service access point.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_ssap(&self) -> Result<u8, GetHeaderError> {
+        self.header
+            .get(LLC_SSAP)
+            .copied()
+            .ok_or(GetHeaderError::OutOfBoundsBufferAccess)
+    }
+
+    /// Control field, 1 byte for U-format PDUs and 2 bytes for I- and S-format PDUs.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_control(&self) -> Result<&[u8], GetHeaderError> {
+        self.header
+            .get(LLC_CONTROL_START..)
+            .ok_or(GetHeaderError::OutOfBoundsBufferAccess)
+    }
+
+    /// True if DSAP and SSAP are 0xAA and a SNAP header follows, see [`SnapHeader`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn is_snap(&self) -> Result<bool, GetHeaderError> {
+        Ok(self.get_dsap()? == LLC_SAP_SNAP && self.get_ssap()? == LLC_SAP_SNAP)
+    }
+}
+
+/// Subnetwork Access Protocol header, follows an [`LlcHeader`] with DSAP and SSAP 0xAA.
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(PartialEq, Eq)]
+pub struct SnapHeader<B> {
+    // size of 5B
+    header: B,
+}
+
+impl<B: PacketBuffer> SnapHeader<B> {
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn new_bpf(buf: B, end: usize) -> Result<(Self, B), SnapBpfCreationError> {
+        let mut cursor = PacketCursor::new(buf, end);
+        let header = Self::from_cursor(&mut cursor)?;
+        Ok((header, cursor.into_inner()))
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_cursor(cursor: &mut PacketCursor<B>) -> Result<Self, SnapBpfCreationError> {
+        // We read the first 5 bytes of the LLC payload:
+        // 3 bytes OUI
+        // 2 bytes protocol ID
+        // ------------------
+        // 5 bytes total
+
+        let header = cursor.split_header_len(SNAP_HEADER_SIZE)?;
+
+        Ok(SnapHeader { header })
+    }
+
+    /// Organizationally unique identifier, 00:00:00 if the protocol ID is an ether type, see
+    /// [`EtherType::lookup`](crate::ethernet::EtherType::lookup).
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_oui(&self) -> Result<&[u8; 3], GetHeaderError> {
+        Ok(self
+            .header
+            .get(SNAP_OUI_START..SNAP_OUI_END)
+            .ok_or(GetHeaderError::OutOfBoundsBufferAccess)?
+            .try_into()?)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_protocol_id(&self) -> Result<u16, GetHeaderError> {
+        Ok(u16::from_be_bytes(
+            self.header
+                .get(SNAP_PROTOCOL_ID_START..SNAP_PROTOCOL_ID_END)
+                .ok_or(GetHeaderError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        ))
+    }
+}
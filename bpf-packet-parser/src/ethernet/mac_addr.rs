@@ -0,0 +1,74 @@
+#[cfg(feature = "fmt")]
+use core::fmt::{Debug, Display, Formatter};
+
+/// Borrowed view of a MAC address inside a frame.
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct MacAddr<'a>(&'a [u8; 6]);
+
+impl<'a> MacAddr<'a> {
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn new(bytes: &'a [u8; 6]) -> Self {
+        MacAddr(bytes)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn as_bytes(&self) -> &'a [u8; 6] {
+        self.0
+    }
+
+    /// ff:ff:ff:ff:ff:ff
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn is_broadcast(&self) -> bool {
+        *self.0 == [0xFF; 6]
+    }
+
+    /// The I/G bit (least significant bit of the first octet) is set, this includes broadcast.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn is_multicast(&self) -> bool {
+        self.0[0] & 0x01 != 0
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn is_unicast(&self) -> bool {
+        !self.is_multicast()
+    }
+
+    /// The U/L bit (second least significant bit of the first octet) is set.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn is_local(&self) -> bool {
+        self.0[0] & 0x02 != 0
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn is_universal(&self) -> bool {
+        !self.is_local()
+    }
+
+    /// Organizationally unique identifier, the first three octets.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn oui(&self) -> [u8; 3] {
+        [self.0[0], self.0[1], self.0[2]]
+    }
+
+    /// 01:80:C2:00:00:00 to 01:80:C2:00:00:0F, reserved by IEEE 802.1Q for link-local
+    /// protocols like STP, LACP or LLDP. Bridges must not forward these.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn is_link_local_reserved(&self) -> bool {
+        matches!(self.0, [0x01, 0x80, 0xC2, 0x00, 0x00, x] if *x <= 0x0F)
+    }
+
+    /// 01:80:C2:00:00:0E, the nearest bridge group address used by LLDP and PTP.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn is_nearest_bridge(&self) -> bool {
+        *self.0 == [0x01, 0x80, 0xC2, 0x00, 0x00, 0x0E]
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for MacAddr<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let [a, b, c, d, e, g] = self.0;
+        write!(f, "{a:02x}:{b:02x}:{c:02x}:{d:02x}:{e:02x}:{g:02x}")
+    }
+}
@@ -0,0 +1,99 @@
+#[cfg(feature = "fmt")]
+use core::fmt::Debug;
+
+/// Tag protocol identifier of an IEEE 802.1Q customer tag (C-tag).
+pub static VLAN_TPID_C_TAG: u16 = 0x8100;
+/// Tag protocol identifier of an IEEE 802.1ad service tag (S-tag).
+pub static VLAN_TPID_S_TAG: u16 = 0x88A8;
+/// Tag protocol identifiers used by pre IEEE 802.1ad QinQ implementations.
+pub static VLAN_TPID_QINQ_9100: u16 = 0x9100;
+pub static VLAN_TPID_QINQ_9200: u16 = 0x9200;
+pub static VLAN_TPID_QINQ_9300: u16 = 0x9300;
+
+/// Returns true if `value` in ether type position introduces a VLAN tag.
+#[cfg_attr(feature = "inline", inline(always))]
+pub fn is_vlan_tpid(value: u16) -> bool {
+    value == VLAN_TPID_C_TAG
+        || value == VLAN_TPID_S_TAG
+        || value == VLAN_TPID_QINQ_9100
+        || value == VLAN_TPID_QINQ_9200
+        || value == VLAN_TPID_QINQ_9300
+}
+
+/// A decoded 4 byte VLAN tag: 2 bytes TPID followed by 2 bytes TCI (3 bits PCP, 1 bit DEI,
+/// 12 bits VID).
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct VlanTag {
+    tpid: u16,
+    tci: u16,
+}
+
+impl VlanTag {
+    /// Creates a new tag, `pcp` is truncated to 3 bits and `vid` to 12 bits.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn new(tpid: u16, pcp: u8, dei: bool, vid: u16) -> Self {
+        VlanTag {
+            tpid,
+            tci: ((pcp as u16 & 0x07) << 13) | ((dei as u16) << 12) | (vid & 0x0FFF),
+        }
+    }
+
+    /// Creates a new IEEE 802.1Q customer tag (TPID 0x8100).
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn new_c_tag(pcp: u8, dei: bool, vid: u16) -> Self {
+        Self::new(VLAN_TPID_C_TAG, pcp, dei, vid)
+    }
+
+    /// Creates a new IEEE 802.1ad service tag (TPID 0x88A8).
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn new_s_tag(pcp: u8, dei: bool, vid: u16) -> Self {
+        Self::new(VLAN_TPID_S_TAG, pcp, dei, vid)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_bytes(bytes: &[u8; 4]) -> Self {
+        VlanTag {
+            tpid: u16::from_be_bytes([bytes[0], bytes[1]]),
+            tci: u16::from_be_bytes([bytes[2], bytes[3]]),
+        }
+    }
+
+    /// Returns the tag as it is laid out on the wire.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn to_bytes(&self) -> [u8; 4] {
+        let [tpid_0, tpid_1] = self.tpid.to_be_bytes();
+        let [tci_0, tci_1] = self.tci.to_be_bytes();
+        [tpid_0, tpid_1, tci_0, tci_1]
+    }
+
+    /// Tag protocol identifier, e.g. 0x8100 for a C-tag or 0x88A8 for an S-tag.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_tpid(&self) -> u16 {
+        self.tpid
+    }
+
+    /// Tag control information, PCP, DEI and VID combined.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_tci(&self) -> u16 {
+        self.tci
+    }
+
+    /// Priority code point (IEEE 802.1p), 3 bits.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_pcp(&self) -> u8 {
+        (self.tci >> 13) as u8
+    }
+
+    /// Drop eligible indicator, 1 bit.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_dei(&self) -> bool {
+        self.tci & 0x1000 != 0
+    }
+
+    /// VLAN identifier, 12 bits.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_vid(&self) -> u16 {
+        self.tci & 0x0FFF
+    }
+}
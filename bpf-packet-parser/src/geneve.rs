@@ -0,0 +1,167 @@
+mod error;
+mod options;
+
+pub use error::*;
+pub use options::*;
+
+use crate::buffer::PacketBuffer;
+use crate::cursor::PacketCursor;
+use crate::ethernet::EtherType;
+use crate::udp::UdpHeader;
+#[cfg(feature = "fmt")]
+use core::fmt::Debug;
+
+// All ranges are [start..end).
+// This means start is included, end is not included.
+pub static VERSION_OPTIONS_LENGTH: usize = 0;
+pub static FLAGS: usize = 1;
+pub static PROTOCOL_TYPE_START: usize = 2;
+pub static PROTOCOL_TYPE_END: usize = 4;
+pub static VNI_START: usize = 4;
+pub static VNI_END: usize = 7;
+pub static OPTIONS_START: usize = 8;
+
+pub static GENEVE_HEADER_SIZE: usize = 8;
+/// IANA assigned UDP destination port.
+pub static GENEVE_UDP_PORT: u16 = 6081;
+/// The options length counts 4 byte words.
+pub static GENEVE_LENGTH_UNIT: usize = 4;
+pub static FLAG_OAM: u8 = 0x80;
+pub static FLAG_CRITICAL: u8 = 0x40;
+
+/// Geneve header including its options, found in the payload of UDP datagrams to
+/// [`GENEVE_UDP_PORT`].
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(PartialEq, Eq)]
+pub struct GeneveHeader<B> {
+    // size of 8B + options length
+    header: B,
+}
+
+impl<B: PacketBuffer> GeneveHeader<B> {
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn new_bpf(buf: B, end: usize) -> Result<(Self, B), GeneveBpfCreationError> {
+        let mut cursor = PacketCursor::new(buf, end);
+        let header = Self::from_cursor(&mut cursor)?;
+        Ok((header, cursor.into_inner()))
+    }
+
+    /// Takes the header and its options from the front of `cursor` and leaves the cursor at
+    /// the payload, see [`GeneveHeader::get_typed_protocol_type`] for its protocol. The UDP
+    /// port is not checked, see [`GeneveHeader::from_udp`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_cursor(cursor: &mut PacketCursor<B>) -> Result<Self, GeneveBpfCreationError> {
+        // We read at least the first 8 bytes of the header:
+        // 2 bits version
+        // 6 bits options length
+        // 1 bit OAM flag
+        // 1 bit critical options flag
+        // 6 bits reserved
+        // 2 bytes protocol type
+        // 3 bytes VNI
+        // 1 byte reserved
+        // ------------------
+        // 8 bytes total
+        // The options take options length * 4 bytes.
+
+        let version_options_length = *cursor
+            .peek::<8>()?
+            .get(VERSION_OPTIONS_LENGTH)
+            .ok_or(GeneveBpfCreationError::OutOfBoundsBufferAccess)?;
+        let version = version_options_length >> 6;
+        if version != 0 {
+            return Err(GeneveBpfCreationError::InvalidVersion { version });
+        }
+
+        let options_len = (version_options_length & 0x3F) as usize * GENEVE_LENGTH_UNIT;
+        let header = cursor.split_header_len(GENEVE_HEADER_SIZE + options_len)?;
+
+        Ok(GeneveHeader { header })
+    }
+
+    /// Like [`GeneveHeader::from_cursor`] for the payload of `udp`, whose destination port
+    /// has to be [`GENEVE_UDP_PORT`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_udp(
+        udp: &UdpHeader<B>,
+        cursor: &mut PacketCursor<B>,
+    ) -> Result<Self, GeneveBpfCreationError> {
+        let port = udp.get_destination_port()?;
+        if port != GENEVE_UDP_PORT {
+            return Err(GeneveBpfCreationError::WrongPort { port });
+        }
+        Self::from_cursor(cursor)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_version(&self) -> Result<u8, GeneveGetHeaderError> {
+        Ok(self.get_u8(VERSION_OPTIONS_LENGTH)? >> 6)
+    }
+
+    /// Length of the options in 4 byte words.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_options_length(&self) -> Result<u8, GeneveGetHeaderError> {
+        Ok(self.get_u8(VERSION_OPTIONS_LENGTH)? & 0x3F)
+    }
+
+    /// Length of the options in bytes.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_options_len(&self) -> Result<usize, GeneveGetHeaderError> {
+        Ok(self.get_options_length()? as usize * GENEVE_LENGTH_UNIT)
+    }
+
+    /// The payload is a control message.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn is_oam(&self) -> Result<bool, GeneveGetHeaderError> {
+        Ok(self.get_u8(FLAGS)? & FLAG_OAM != 0)
+    }
+
+    /// At least one option has the critical bit set. Tunnel endpoints which don't understand
+    /// a critical option have to drop the packet.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn has_critical_options(&self) -> Result<bool, GeneveGetHeaderError> {
+        Ok(self.get_u8(FLAGS)? & FLAG_CRITICAL != 0)
+    }
+
+    /// Ether type of the payload, usually [`EtherType::TransparentEthernetBridging`] for
+    /// Ethernet frames.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_protocol_type(&self) -> Result<u16, GeneveGetHeaderError> {
+        Ok(u16::from_be_bytes(
+            self.header
+                .get(PROTOCOL_TYPE_START..PROTOCOL_TYPE_END)
+                .ok_or(GeneveGetHeaderError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        ))
+    }
+
+    /// The protocol type looked up with [`EtherType::lookup`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_typed_protocol_type(&self) -> Result<EtherType, GeneveGetHeaderError> {
+        Ok(EtherType::lookup(self.get_protocol_type()?)?)
+    }
+
+    /// Virtual network identifier, 24 bits.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_vni(&self) -> Result<u32, GeneveGetHeaderError> {
+        let vni: &[u8; 3] = self
+            .header
+            .get(VNI_START..VNI_END)
+            .ok_or(GeneveGetHeaderError::OutOfBoundsBufferAccess)?
+            .try_into()?;
+        Ok(u32::from_be_bytes([0, vni[0], vni[1], vni[2]]))
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn options(&self) -> GeneveOptionsIter<'_> {
+        GeneveOptionsIter::new(self.header.get(OPTIONS_START..).unwrap_or_default())
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn get_u8(&self, index: usize) -> Result<u8, GeneveGetHeaderError> {
+        self.header
+            .get(index)
+            .copied()
+            .ok_or(GeneveGetHeaderError::OutOfBoundsBufferAccess)
+    }
+}
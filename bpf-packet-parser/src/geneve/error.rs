@@ -0,0 +1,220 @@
+use crate::cursor::CursorError;
+use crate::ethernet::EtherTypeParsingError;
+use crate::udp::UdpGetHeaderError;
+use core::array::TryFromSliceError;
+#[cfg(feature = "fmt")]
+use core::fmt::{Debug, Display, Formatter};
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum GeneveError {
+    BpfCreation(GeneveBpfCreationError),
+    GetHeader(GeneveGetHeaderError),
+    Option(GeneveOptionError),
+}
+
+#[cfg(feature = "fmt")]
+impl Display for GeneveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            GeneveError::BpfCreation(err) => {
+                write!(f, "{err}")
+            }
+            GeneveError::GetHeader(err) => {
+                write!(f, "{err}")
+            }
+            GeneveError::Option(err) => {
+                write!(f, "{err}")
+            }
+        }
+    }
+}
+
+impl From<GeneveBpfCreationError> for GeneveError {
+    fn from(value: GeneveBpfCreationError) -> Self {
+        Self::BpfCreation(value)
+    }
+}
+
+impl From<GeneveGetHeaderError> for GeneveError {
+    fn from(value: GeneveGetHeaderError) -> Self {
+        Self::GetHeader(value)
+    }
+}
+
+impl From<GeneveOptionError> for GeneveError {
+    fn from(value: GeneveOptionError) -> Self {
+        Self::Option(value)
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for GeneveError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum GeneveBpfCreationError {
+    WrongPort { port: u16 },
+    InvalidVersion { version: u8 },
+    HeaderTooShort { size: usize, expected: usize },
+    BpfOutOfBounds,
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for GeneveBpfCreationError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+impl From<CursorError> for GeneveBpfCreationError {
+    fn from(value: CursorError) -> Self {
+        match value {
+            CursorError::TooShort { size, expected } => Self::HeaderTooShort { size, expected },
+            CursorError::BpfOutOfBounds => Self::BpfOutOfBounds,
+            CursorError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            CursorError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+impl From<UdpGetHeaderError> for GeneveBpfCreationError {
+    fn from(value: UdpGetHeaderError) -> Self {
+        match value {
+            UdpGetHeaderError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            UdpGetHeaderError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for GeneveBpfCreationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::WrongPort { port } => {
+                write!(f, "UDP destination port expected to be 6081, was: {port}")
+            }
+            Self::InvalidVersion { version } => {
+                write!(f, "Geneve version expected to be 0, was: {version}")
+            }
+            Self::HeaderTooShort { size, expected } => {
+                write!(
+                    f,
+                    "Geneve header expected to be at least {expected} bytes, was: {size}"
+                )
+            }
+            Self::BpfOutOfBounds => {
+                write!(f, "Bpf out of bounds")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for GeneveBpfCreationError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum GeneveGetHeaderError {
+    NoRecognizedProtocolType { protocol_type: u16 },
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for GeneveGetHeaderError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+impl From<EtherTypeParsingError> for GeneveGetHeaderError {
+    fn from(value: EtherTypeParsingError) -> Self {
+        match value {
+            EtherTypeParsingError::NoRecognizedEtherType { ether_type } => {
+                Self::NoRecognizedProtocolType {
+                    protocol_type: ether_type,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for GeneveGetHeaderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NoRecognizedProtocolType { protocol_type } => {
+                write!(
+                    f,
+                    "No valid Geneve protocol type, was: {protocol_type:#06X}"
+                )
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for GeneveGetHeaderError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum GeneveOptionError {
+    TruncatedHeader {
+        size: usize,
+    },
+    Truncated {
+        class: u16,
+        option_type: u8,
+        length: u8,
+    },
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for GeneveOptionError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for GeneveOptionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::TruncatedHeader { size } => {
+                write!(
+                    f,
+                    "Geneve option header expected to be 4 bytes, was: {size}"
+                )
+            }
+            Self::Truncated {
+                class,
+                option_type,
+                length,
+            } => {
+                write!(
+                    f,
+                    "Geneve option {class:#06X}/{option_type:#04X} with length {length} exceeds the options"
+                )
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for GeneveOptionError {}
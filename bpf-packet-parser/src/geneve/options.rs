@@ -0,0 +1,104 @@
+use crate::geneve::{GeneveOptionError, GENEVE_LENGTH_UNIT};
+use crate::tlv::{TlvIter, TlvParser};
+#[cfg(feature = "fmt")]
+use core::fmt::Debug;
+
+pub static GENEVE_OPTION_HEADER_SIZE: usize = 4;
+/// The options of a header take at most 252 bytes, which leaves room for 63 options.
+pub const MAX_GENEVE_OPTIONS: usize = 63;
+/// Set in the option type of options which have to be understood by the receiver.
+pub static OPTION_TYPE_CRITICAL: u8 = 0x80;
+
+/// A Geneve option: 2 bytes class, 1 byte type, 3 bits reserved, 5 bits length and the data.
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct GeneveOption<'a> {
+    class: u16,
+    option_type: u8,
+    data: &'a [u8],
+}
+
+impl<'a> GeneveOption<'a> {
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_class(&self) -> u16 {
+        self.class
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_type(&self) -> u8 {
+        self.option_type
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn is_critical(&self) -> bool {
+        self.option_type & OPTION_TYPE_CRITICAL != 0
+    }
+
+    /// Length of the data in bytes.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_len(&self) -> usize {
+        self.data.len()
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_data(&self) -> &'a [u8] {
+        self.data
+    }
+}
+
+/// Iterator over the options of a Geneve header.
+pub type GeneveOptionsIter<'a> = TlvIter<'a, GeneveOptionParser>;
+
+/// Parses the options of a [`GeneveOptionsIter`].
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Default)]
+pub struct GeneveOptionParser;
+
+impl<'a> TlvParser<'a> for GeneveOptionParser {
+    type Item = GeneveOption<'a>;
+    type Error = GeneveOptionError;
+
+    const MAX: usize = MAX_GENEVE_OPTIONS;
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn parse(
+        &mut self,
+        options: &'a [u8],
+        offset: &mut usize,
+    ) -> Option<Result<GeneveOption<'a>, GeneveOptionError>> {
+        Some(parse_option(options, offset))
+    }
+}
+
+#[cfg_attr(feature = "inline", inline(always))]
+fn parse_option<'a>(
+    options: &'a [u8],
+    offset: &mut usize,
+) -> Result<GeneveOption<'a>, GeneveOptionError> {
+    let header: &[u8; 4] = options
+        .get(*offset..*offset + GENEVE_OPTION_HEADER_SIZE)
+        .ok_or(GeneveOptionError::TruncatedHeader {
+            size: options.len() - *offset,
+        })?
+        .try_into()?;
+    let class = u16::from_be_bytes([header[0], header[1]]);
+    let option_type = header[2];
+    let length = header[3] & 0x1F;
+
+    let data_start = *offset + GENEVE_OPTION_HEADER_SIZE;
+    let data_end = data_start + length as usize * GENEVE_LENGTH_UNIT;
+    let data = options
+        .get(data_start..data_end)
+        .ok_or(GeneveOptionError::Truncated {
+            class,
+            option_type,
+            length,
+        })?;
+
+    *offset = data_end;
+    Ok(GeneveOption {
+        class,
+        option_type,
+        data,
+    })
+}
@@ -0,0 +1,220 @@
+mod error;
+mod erspan;
+
+pub use error::*;
+pub use erspan::*;
+
+use crate::buffer::PacketBuffer;
+use crate::cursor::PacketCursor;
+use crate::ethernet::EtherType;
+#[cfg(feature = "fmt")]
+use core::fmt::Debug;
+
+// All ranges are [start..end).
+// This means start is included, end is not included.
+pub static FLAGS: usize = 0;
+pub static FLAGS_VERSION: usize = 1;
+pub static PROTOCOL_TYPE_START: usize = 2;
+pub static PROTOCOL_TYPE_END: usize = 4;
+pub static OPTIONAL_FIELDS_START: usize = 4;
+
+pub static GRE_MIN_HEADER_SIZE: usize = 4;
+pub static GRE_MAX_HEADER_SIZE: usize = 16;
+/// Checksum, key and sequence number take 4 bytes each.
+pub static GRE_OPTIONAL_FIELD_SIZE: usize = 4;
+pub static FLAG_CHECKSUM: u8 = 0x80;
+/// Routing present, only defined by the deprecated RFC 1701.
+pub static FLAG_ROUTING: u8 = 0x40;
+pub static FLAG_KEY: u8 = 0x20;
+pub static FLAG_SEQUENCE: u8 = 0x10;
+
+/// GRE header including checksum, key and sequence number if present (RFC 2784 and
+/// RFC 2890), found in the payload of IP packets with protocol [`IpProtocol::Gre`].
+///
+/// The payload stays in the cursor. Depending on [`GreHeader::get_typed_protocol_type`] it is
+/// parsed with [`EtherFrame::from_cursor`] ([`EtherType::TransparentEthernetBridging`], e.g.
+/// NVGRE), an IP parser or [`ErspanHeader::from_gre`].
+///
+/// [`IpProtocol::Gre`]: crate::ip::IpProtocol::Gre
+/// [`EtherFrame::from_cursor`]: crate::ethernet::EtherFrame::from_cursor
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(PartialEq, Eq)]
+pub struct GreHeader<B> {
+    // size of 4B to 16B
+    header: B,
+}
+
+impl<B: PacketBuffer> GreHeader<B> {
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn new_bpf(buf: B, end: usize) -> Result<(Self, B), GreBpfCreationError> {
+        let mut cursor = PacketCursor::new(buf, end);
+        let header = Self::from_cursor(&mut cursor)?;
+        Ok((header, cursor.into_inner()))
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_cursor(cursor: &mut PacketCursor<B>) -> Result<Self, GreBpfCreationError> {
+        // We read at least the first 4 bytes of the header:
+        // 1 bit checksum present
+        // 1 bit routing present
+        // 1 bit key present
+        // 1 bit sequence number present
+        // 9 bits reserved
+        // 3 bits version
+        // 2 bytes protocol type
+        // ------------------
+        // 4 bytes total
+        // Checksum and reserved, key and sequence number add 4 bytes each if present.
+
+        let header = cursor.peek::<4>()?;
+        let flags = *header
+            .get(FLAGS)
+            .ok_or(GreBpfCreationError::OutOfBoundsBufferAccess)?;
+        let version = *header
+            .get(FLAGS_VERSION)
+            .ok_or(GreBpfCreationError::OutOfBoundsBufferAccess)?
+            & 0x07;
+        if version != 0 {
+            return Err(GreBpfCreationError::InvalidVersion { version });
+        }
+        if flags & FLAG_ROUTING != 0 {
+            return Err(GreBpfCreationError::RoutingPresent);
+        }
+
+        let header_len = GRE_MIN_HEADER_SIZE + optional_fields_len(flags, 0xFF);
+        let header = cursor.split_header_len(header_len)?;
+
+        Ok(GreHeader { header })
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_flags(&self) -> Result<u8, GreGetHeaderError> {
+        self.header
+            .get(FLAGS)
+            .copied()
+            .ok_or(GreGetHeaderError::OutOfBoundsBufferAccess)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_version(&self) -> Result<u8, GreGetHeaderError> {
+        Ok(self
+            .header
+            .get(FLAGS_VERSION)
+            .ok_or(GreGetHeaderError::OutOfBoundsBufferAccess)?
+            & 0x07)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn has_checksum(&self) -> Result<bool, GreGetHeaderError> {
+        Ok(self.get_flags()? & FLAG_CHECKSUM != 0)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn has_key(&self) -> Result<bool, GreGetHeaderError> {
+        Ok(self.get_flags()? & FLAG_KEY != 0)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn has_sequence_number(&self) -> Result<bool, GreGetHeaderError> {
+        Ok(self.get_flags()? & FLAG_SEQUENCE != 0)
+    }
+
+    /// Length of the header including the optional fields.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_header_len(&self) -> usize {
+        self.header.len()
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_protocol_type(&self) -> Result<u16, GreGetHeaderError> {
+        Ok(u16::from_be_bytes(
+            self.header
+                .get(PROTOCOL_TYPE_START..PROTOCOL_TYPE_END)
+                .ok_or(GreGetHeaderError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        ))
+    }
+
+    /// The protocol type looked up with [`EtherType::lookup`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_typed_protocol_type(&self) -> Result<EtherType, GreGetHeaderError> {
+        Ok(EtherType::lookup(self.get_protocol_type()?)?)
+    }
+
+    /// Checksum over header and payload, `None` if the checksum present flag isn't set.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_checksum(&self) -> Result<Option<u16>, GreGetHeaderError> {
+        let flags = self.get_flags()?;
+        if flags & FLAG_CHECKSUM == 0 {
+            return Ok(None);
+        }
+        let start = OPTIONAL_FIELDS_START;
+        Ok(Some(u16::from_be_bytes(
+            self.header
+                .get(start..start + 2)
+                .ok_or(GreGetHeaderError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        )))
+    }
+
+    /// `None` if the key present flag isn't set.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_key(&self) -> Result<Option<u32>, GreGetHeaderError> {
+        let flags = self.get_flags()?;
+        if flags & FLAG_KEY == 0 {
+            return Ok(None);
+        }
+        let start = OPTIONAL_FIELDS_START + optional_fields_len(flags, FLAG_CHECKSUM);
+        self.get_u32(start).map(Some)
+    }
+
+    /// `None` if the sequence number present flag isn't set.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_sequence_number(&self) -> Result<Option<u32>, GreGetHeaderError> {
+        let flags = self.get_flags()?;
+        if flags & FLAG_SEQUENCE == 0 {
+            return Ok(None);
+        }
+        let start = OPTIONAL_FIELDS_START + optional_fields_len(flags, FLAG_CHECKSUM | FLAG_KEY);
+        self.get_u32(start).map(Some)
+    }
+
+    /// NVGRE (RFC 7637) carries Ethernet frames with a key.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn is_nvgre(&self) -> Result<bool, GreGetHeaderError> {
+        Ok(self.has_key()?
+            && self.get_protocol_type()? == EtherType::TransparentEthernetBridging as u16)
+    }
+
+    /// NVGRE virtual subnet ID, the upper 24 bits of the key. `None` without key.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_vsid(&self) -> Result<Option<u32>, GreGetHeaderError> {
+        Ok(self.get_key()?.map(|key| key >> 8))
+    }
+
+    /// NVGRE flow ID, the lower 8 bits of the key. `None` without key.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_flow_id(&self) -> Result<Option<u8>, GreGetHeaderError> {
+        Ok(self.get_key()?.map(|key| key as u8))
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn get_u32(&self, start: usize) -> Result<u32, GreGetHeaderError> {
+        Ok(u32::from_be_bytes(
+            self.header
+                .get(start..start + 4)
+                .ok_or(GreGetHeaderError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        ))
+    }
+}
+
+/// Length of the optional fields whose present flags are set in both `flags` and `mask`.
+#[cfg_attr(feature = "inline", inline(always))]
+fn optional_fields_len(flags: u8, mask: u8) -> usize {
+    let flags = flags & mask;
+    ((flags & FLAG_CHECKSUM != 0) as usize
+        + (flags & FLAG_KEY != 0) as usize
+        + (flags & FLAG_SEQUENCE != 0) as usize)
+        * GRE_OPTIONAL_FIELD_SIZE
+}
@@ -0,0 +1,276 @@
+use crate::cursor::CursorError;
+use crate::ethernet::EtherTypeParsingError;
+use core::array::TryFromSliceError;
+#[cfg(feature = "fmt")]
+use core::fmt::{Debug, Display, Formatter};
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum GreError {
+    BpfCreation(GreBpfCreationError),
+    GetHeader(GreGetHeaderError),
+    ErspanBpfCreation(ErspanBpfCreationError),
+    ErspanGetHeader(ErspanGetHeaderError),
+}
+
+#[cfg(feature = "fmt")]
+impl Display for GreError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            GreError::BpfCreation(err) => {
+                write!(f, "{err}")
+            }
+            GreError::GetHeader(err) => {
+                write!(f, "{err}")
+            }
+            GreError::ErspanBpfCreation(err) => {
+                write!(f, "{err}")
+            }
+            GreError::ErspanGetHeader(err) => {
+                write!(f, "{err}")
+            }
+        }
+    }
+}
+
+impl From<GreBpfCreationError> for GreError {
+    fn from(value: GreBpfCreationError) -> Self {
+        Self::BpfCreation(value)
+    }
+}
+
+impl From<GreGetHeaderError> for GreError {
+    fn from(value: GreGetHeaderError) -> Self {
+        Self::GetHeader(value)
+    }
+}
+
+impl From<ErspanBpfCreationError> for GreError {
+    fn from(value: ErspanBpfCreationError) -> Self {
+        Self::ErspanBpfCreation(value)
+    }
+}
+
+impl From<ErspanGetHeaderError> for GreError {
+    fn from(value: ErspanGetHeaderError) -> Self {
+        Self::ErspanGetHeader(value)
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for GreError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum GreBpfCreationError {
+    InvalidVersion { version: u8 },
+    RoutingPresent,
+    HeaderTooShort { size: usize, expected: usize },
+    BpfOutOfBounds,
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for GreBpfCreationError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+impl From<CursorError> for GreBpfCreationError {
+    fn from(value: CursorError) -> Self {
+        match value {
+            CursorError::TooShort { size, expected } => Self::HeaderTooShort { size, expected },
+            CursorError::BpfOutOfBounds => Self::BpfOutOfBounds,
+            CursorError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            CursorError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for GreBpfCreationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidVersion { version } => {
+                write!(f, "GRE version expected to be 0, was: {version}")
+            }
+            Self::RoutingPresent => {
+                write!(f, "GRE routing is not supported")
+            }
+            Self::HeaderTooShort { size, expected } => {
+                write!(
+                    f,
+                    "GRE header expected to be at least {expected} bytes, was: {size}"
+                )
+            }
+            Self::BpfOutOfBounds => {
+                write!(f, "Bpf out of bounds")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for GreBpfCreationError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum GreGetHeaderError {
+    NoRecognizedProtocolType { protocol_type: u16 },
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for GreGetHeaderError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+impl From<EtherTypeParsingError> for GreGetHeaderError {
+    fn from(value: EtherTypeParsingError) -> Self {
+        match value {
+            EtherTypeParsingError::NoRecognizedEtherType { ether_type } => {
+                Self::NoRecognizedProtocolType {
+                    protocol_type: ether_type,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for GreGetHeaderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NoRecognizedProtocolType { protocol_type } => {
+                write!(f, "No valid GRE protocol type, was: {protocol_type:#06X}")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for GreGetHeaderError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum ErspanBpfCreationError {
+    WrongProtocolType { protocol_type: u16 },
+    NoErspanHeader,
+    UnsupportedVersion { version: u8 },
+    HeaderTooShort { size: usize, expected: usize },
+    BpfOutOfBounds,
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for ErspanBpfCreationError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+impl From<CursorError> for ErspanBpfCreationError {
+    fn from(value: CursorError) -> Self {
+        match value {
+            CursorError::TooShort { size, expected } => Self::HeaderTooShort { size, expected },
+            CursorError::BpfOutOfBounds => Self::BpfOutOfBounds,
+            CursorError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            CursorError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+impl From<GreGetHeaderError> for ErspanBpfCreationError {
+    fn from(value: GreGetHeaderError) -> Self {
+        match value {
+            GreGetHeaderError::NoRecognizedProtocolType { protocol_type } => {
+                Self::WrongProtocolType { protocol_type }
+            }
+            GreGetHeaderError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            GreGetHeaderError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for ErspanBpfCreationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::WrongProtocolType { protocol_type } => {
+                write!(
+                    f,
+                    "GRE protocol type expected to be ERSPAN, was: {protocol_type:#06X}"
+                )
+            }
+            Self::NoErspanHeader => {
+                write!(f, "ERSPAN type I has no ERSPAN header")
+            }
+            Self::UnsupportedVersion { version } => {
+                write!(f, "ERSPAN version expected to be 1 or 2, was: {version}")
+            }
+            Self::HeaderTooShort { size, expected } => {
+                write!(
+                    f,
+                    "ERSPAN header expected to be at least {expected} bytes, was: {size}"
+                )
+            }
+            Self::BpfOutOfBounds => {
+                write!(f, "Bpf out of bounds")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for ErspanBpfCreationError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum ErspanGetHeaderError {
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for ErspanGetHeaderError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for ErspanGetHeaderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for ErspanGetHeaderError {}
@@ -0,0 +1,243 @@
+use crate::buffer::PacketBuffer;
+use crate::cursor::PacketCursor;
+use crate::ethernet::EtherType;
+use crate::gre::{ErspanBpfCreationError, ErspanGetHeaderError, GreHeader};
+#[cfg(feature = "fmt")]
+use core::fmt::Debug;
+
+// All ranges are [start..end).
+// This means start is included, end is not included.
+pub static ERSPAN_VERSION_VLAN_START: usize = 0;
+pub static ERSPAN_VERSION_VLAN_END: usize = 2;
+pub static ERSPAN_COS_SESSION_ID_START: usize = 2;
+pub static ERSPAN_COS_SESSION_ID_END: usize = 4;
+// Type II
+pub static ERSPAN_INDEX_START: usize = 4;
+pub static ERSPAN_INDEX_END: usize = 8;
+// Type III
+pub static ERSPAN_TIMESTAMP_START: usize = 4;
+pub static ERSPAN_TIMESTAMP_END: usize = 8;
+pub static ERSPAN_SGT_START: usize = 8;
+pub static ERSPAN_SGT_END: usize = 10;
+pub static ERSPAN_HW_ID: usize = 10;
+pub static ERSPAN_FLAGS: usize = 11;
+
+/// Version field of ERSPAN type II headers.
+pub static ERSPAN_VERSION_TYPE_2: u8 = 1;
+/// Version field of ERSPAN type III headers.
+pub static ERSPAN_VERSION_TYPE_3: u8 = 2;
+pub static ERSPAN_TYPE_2_HEADER_SIZE: usize = 8;
+pub static ERSPAN_TYPE_3_HEADER_SIZE: usize = 12;
+/// Size of the optional platform specific subheader of type III headers.
+pub static ERSPAN_PLATFORM_SUBHEADER_SIZE: usize = 8;
+pub static FLAG_TRUNCATED: u8 = 0x04;
+pub static FLAG_EGRESS: u8 = 0x08;
+pub static FLAG_PLATFORM_SUBHEADER: u8 = 0x01;
+
+/// ERSPAN type II or type III header, including the platform specific subheader of type III
+/// if present. The payload is the mirrored Ethernet frame.
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(PartialEq, Eq)]
+pub struct ErspanHeader<B> {
+    // size of 8B, 12B or 20B
+    header: B,
+}
+
+impl<B: PacketBuffer> ErspanHeader<B> {
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn new_bpf(buf: B, end: usize) -> Result<(Self, B), ErspanBpfCreationError> {
+        let mut cursor = PacketCursor::new(buf, end);
+        let header = Self::from_cursor(&mut cursor)?;
+        Ok((header, cursor.into_inner()))
+    }
+
+    /// Takes a type II or type III header, as told by its version field, from the front of
+    /// `cursor` and leaves the cursor at the mirrored frame.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_cursor(cursor: &mut PacketCursor<B>) -> Result<Self, ErspanBpfCreationError> {
+        // We read at most the first 12 bytes of the header:
+        // 4 bits version
+        // 12 bits VLAN
+        // 3 bits COS
+        // 2 bits encapsulation type
+        // 1 bit truncated
+        // 10 bits session ID
+        // 4 bytes index (type II) or timestamp (type III)
+        // 2 bytes SGT (type III)
+        // 2 bytes flags and hardware ID (type III)
+        // ------------------
+        // 12 bytes total
+        // The platform specific subheader adds 8 bytes if present.
+
+        let [version_vlan, _] = *cursor.peek::<2>()?;
+        let version = version_vlan >> 4;
+
+        let header_len = if version == ERSPAN_VERSION_TYPE_2 {
+            ERSPAN_TYPE_2_HEADER_SIZE
+        } else if version == ERSPAN_VERSION_TYPE_3 {
+            let flags = *cursor
+                .peek::<12>()?
+                .get(ERSPAN_FLAGS)
+                .ok_or(ErspanBpfCreationError::OutOfBoundsBufferAccess)?;
+            if flags & FLAG_PLATFORM_SUBHEADER != 0 {
+                ERSPAN_TYPE_3_HEADER_SIZE + ERSPAN_PLATFORM_SUBHEADER_SIZE
+            } else {
+                ERSPAN_TYPE_3_HEADER_SIZE
+            }
+        } else {
+            return Err(ErspanBpfCreationError::UnsupportedVersion { version });
+        };
+
+        let header = cursor.split_header_len(header_len)?;
+
+        Ok(ErspanHeader { header })
+    }
+
+    /// Like [`ErspanHeader::from_cursor`] for the payload of `gre`, whose protocol type has to
+    /// be [`EtherType::ErspanType2`] or [`EtherType::ErspanType3`] matching the version.
+    ///
+    /// ERSPAN type I uses [`EtherType::ErspanType2`] without a sequence number and has no
+    /// ERSPAN header, its payload is the mirrored frame directly. It fails with
+    /// [`ErspanBpfCreationError::NoErspanHeader`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_gre(
+        gre: &GreHeader<B>,
+        cursor: &mut PacketCursor<B>,
+    ) -> Result<Self, ErspanBpfCreationError> {
+        let protocol_type = gre.get_protocol_type()?;
+        let expected_version = if protocol_type == EtherType::ErspanType2 as u16 {
+            if !gre.has_sequence_number()? {
+                return Err(ErspanBpfCreationError::NoErspanHeader);
+            }
+            ERSPAN_VERSION_TYPE_2
+        } else if protocol_type == EtherType::ErspanType3 as u16 {
+            ERSPAN_VERSION_TYPE_3
+        } else {
+            return Err(ErspanBpfCreationError::WrongProtocolType { protocol_type });
+        };
+
+        let [version_vlan, _] = *cursor.peek::<2>()?;
+        let version = version_vlan >> 4;
+        if version != expected_version {
+            return Err(ErspanBpfCreationError::UnsupportedVersion { version });
+        }
+        Self::from_cursor(cursor)
+    }
+
+    /// 1 for type II, 2 for type III.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_version(&self) -> Result<u8, ErspanGetHeaderError> {
+        Ok((self.get_u16(ERSPAN_VERSION_VLAN_START, ERSPAN_VERSION_VLAN_END)? >> 12) as u8)
+    }
+
+    /// VLAN of the mirrored frame, 12 bits.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_vlan(&self) -> Result<u16, ErspanGetHeaderError> {
+        Ok(self.get_u16(ERSPAN_VERSION_VLAN_START, ERSPAN_VERSION_VLAN_END)? & 0x0FFF)
+    }
+
+    /// Class of service of the mirrored frame, 3 bits.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_cos(&self) -> Result<u8, ErspanGetHeaderError> {
+        Ok((self.get_u16(ERSPAN_COS_SESSION_ID_START, ERSPAN_COS_SESSION_ID_END)? >> 13) as u8)
+    }
+
+    /// The mirrored frame was truncated.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn is_truncated(&self) -> Result<bool, ErspanGetHeaderError> {
+        Ok(self.get_u8(ERSPAN_COS_SESSION_ID_START)? & FLAG_TRUNCATED != 0)
+    }
+
+    /// 10 bits.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_session_id(&self) -> Result<u16, ErspanGetHeaderError> {
+        Ok(self.get_u16(ERSPAN_COS_SESSION_ID_START, ERSPAN_COS_SESSION_ID_END)? & 0x03FF)
+    }
+
+    /// Port index of the mirrored frame, 20 bits. `None` for type III.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_index(&self) -> Result<Option<u32>, ErspanGetHeaderError> {
+        if self.get_version()? != ERSPAN_VERSION_TYPE_2 {
+            return Ok(None);
+        }
+        Ok(Some(
+            self.get_u32(ERSPAN_INDEX_START, ERSPAN_INDEX_END)? & 0x000F_FFFF,
+        ))
+    }
+
+    /// Timestamp in units of [`ErspanHeader::get_granularity`]. `None` for type II.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_timestamp(&self) -> Result<Option<u32>, ErspanGetHeaderError> {
+        if self.get_version()? != ERSPAN_VERSION_TYPE_3 {
+            return Ok(None);
+        }
+        Ok(Some(
+            self.get_u32(ERSPAN_TIMESTAMP_START, ERSPAN_TIMESTAMP_END)?,
+        ))
+    }
+
+    /// Security group tag. `None` for type II.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_sgt(&self) -> Result<Option<u16>, ErspanGetHeaderError> {
+        if self.get_version()? != ERSPAN_VERSION_TYPE_3 {
+            return Ok(None);
+        }
+        Ok(Some(self.get_u16(ERSPAN_SGT_START, ERSPAN_SGT_END)?))
+    }
+
+    /// 6 bits. `None` for type II.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_hardware_id(&self) -> Result<Option<u8>, ErspanGetHeaderError> {
+        if self.get_version()? != ERSPAN_VERSION_TYPE_3 {
+            return Ok(None);
+        }
+        let hw_id = self.get_u16(ERSPAN_HW_ID, ERSPAN_FLAGS + 1)?;
+        Ok(Some(((hw_id >> 4) & 0x3F) as u8))
+    }
+
+    /// The frame was mirrored on egress instead of ingress. `None` for type II.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn is_egress(&self) -> Result<Option<bool>, ErspanGetHeaderError> {
+        if self.get_version()? != ERSPAN_VERSION_TYPE_3 {
+            return Ok(None);
+        }
+        Ok(Some(self.get_u8(ERSPAN_FLAGS)? & FLAG_EGRESS != 0))
+    }
+
+    /// Timestamp granularity, 2 bits. `None` for type II.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_granularity(&self) -> Result<Option<u8>, ErspanGetHeaderError> {
+        if self.get_version()? != ERSPAN_VERSION_TYPE_3 {
+            return Ok(None);
+        }
+        Ok(Some((self.get_u8(ERSPAN_FLAGS)? >> 1) & 0x03))
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn get_u8(&self, index: usize) -> Result<u8, ErspanGetHeaderError> {
+        self.header
+            .get(index)
+            .copied()
+            .ok_or(ErspanGetHeaderError::OutOfBoundsBufferAccess)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn get_u16(&self, start: usize, end: usize) -> Result<u16, ErspanGetHeaderError> {
+        Ok(u16::from_be_bytes(
+            self.header
+                .get(start..end)
+                .ok_or(ErspanGetHeaderError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        ))
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn get_u32(&self, start: usize, end: usize) -> Result<u32, ErspanGetHeaderError> {
+        Ok(u32::from_be_bytes(
+            self.header
+                .get(start..end)
+                .ok_or(ErspanGetHeaderError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        ))
+    }
+}
@@ -0,0 +1,402 @@
+mod error;
+mod header_values;
+
+pub use error::*;
+pub use header_values::*;
+
+use crate::buffer::PacketBuffer;
+use crate::cursor::PacketCursor;
+use crate::ipv4::Ipv4Header;
+use crate::ipv6::Ipv6Header;
+use crate::tlv::{TlvIter, TlvParser};
+use crate::udp::UdpHeader;
+#[cfg(feature = "fmt")]
+use core::fmt::Debug;
+
+// All ranges are [start..end).
+// This means start is included, end is not included.
+pub static FLAGS: usize = 0;
+pub static MESSAGE_TYPE: usize = 1;
+pub static LENGTH_START: usize = 2;
+pub static LENGTH_END: usize = 4;
+pub static TEID_START: usize = 4;
+pub static TEID_END: usize = 8;
+pub static SEQUENCE_NUMBER_START: usize = 8;
+pub static SEQUENCE_NUMBER_END: usize = 10;
+pub static N_PDU_NUMBER: usize = 10;
+pub static NEXT_EXTENSION_HEADER_TYPE: usize = 11;
+pub static EXTENSION_HEADERS_START: usize = 12;
+
+pub static GTPU_MIN_HEADER_SIZE: usize = 8;
+/// Size of the header with sequence number, N-PDU number and next extension header type.
+pub static GTPU_OPTIONAL_HEADER_SIZE: usize = 12;
+/// Extension header lengths count 4 byte words.
+pub static GTPU_LENGTH_UNIT: usize = 4;
+/// IANA assigned UDP destination port.
+pub static GTPU_UDP_PORT: u16 = 2152;
+pub static GTPU_VERSION: u8 = 1;
+pub static FLAG_PROTOCOL_TYPE: u8 = 0x10;
+pub static FLAG_EXTENSION_HEADER: u8 = 0x04;
+pub static FLAG_SEQUENCE_NUMBER: u8 = 0x02;
+pub static FLAG_N_PDU_NUMBER: u8 = 0x01;
+
+/// Maximum amount of extension headers walked, longer chains fail.
+pub const MAX_GTPU_EXTENSION_HEADERS: usize = 4;
+
+/// GTP-U header including the optional fields and all extension headers, found in the
+/// payload of UDP datagrams to [`GTPU_UDP_PORT`].
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(PartialEq, Eq)]
+pub struct GtpuHeader<B> {
+    // size of 8B, 12B or 12B + extension headers
+    header: B,
+}
+
+impl<B: PacketBuffer> GtpuHeader<B> {
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn new_bpf(buf: B, end: usize) -> Result<(Self, B), GtpuBpfCreationError> {
+        let mut cursor = PacketCursor::new(buf, end);
+        let header = Self::from_cursor(&mut cursor)?;
+        Ok((header, cursor.into_inner()))
+    }
+
+    /// Takes the header, its optional fields and the extension header chain from the front
+    /// of `cursor` and leaves the cursor at the T-PDU, see [`GtpuHeader::split_inner_ip`].
+    /// The UDP port is not checked, see [`GtpuHeader::from_udp`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_cursor(cursor: &mut PacketCursor<B>) -> Result<Self, GtpuBpfCreationError> {
+        // We read at least the first 8 bytes of the header:
+        // 3 bits version
+        // 1 bit protocol type
+        // 1 bit reserved
+        // 1 bit extension header flag
+        // 1 bit sequence number flag
+        // 1 bit N-PDU number flag
+        // 1 byte message type
+        // 2 bytes length
+        // 4 bytes TEID
+        // ------------------
+        // 8 bytes total
+        // If any flag is set, 2 bytes sequence number, 1 byte N-PDU number and 1 byte next
+        // extension header type follow. Every extension header takes length * 4 bytes.
+
+        let header = cursor.peek::<8>()?;
+        let flags = *header
+            .get(FLAGS)
+            .ok_or(GtpuBpfCreationError::OutOfBoundsBufferAccess)?;
+        let version = flags >> 5;
+        if version != GTPU_VERSION {
+            return Err(GtpuBpfCreationError::InvalidVersion { version });
+        }
+        if flags & FLAG_PROTOCOL_TYPE == 0 {
+            return Err(GtpuBpfCreationError::GtpPrime);
+        }
+        let length = u16::from_be_bytes(
+            header
+                .get(LENGTH_START..LENGTH_END)
+                .ok_or(GtpuBpfCreationError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        );
+        let size = cursor.len() - GTPU_MIN_HEADER_SIZE;
+        if length as usize > size {
+            return Err(GtpuBpfCreationError::LengthExceedsPayload { length, size });
+        }
+
+        let header_len =
+            if flags & (FLAG_EXTENSION_HEADER | FLAG_SEQUENCE_NUMBER | FLAG_N_PDU_NUMBER) == 0 {
+                GTPU_MIN_HEADER_SIZE
+            } else if flags & FLAG_EXTENSION_HEADER == 0 {
+                GTPU_OPTIONAL_HEADER_SIZE
+            } else {
+                walk_extension_headers(cursor)?
+            };
+        if header_len - GTPU_MIN_HEADER_SIZE > length as usize {
+            return Err(GtpuBpfCreationError::LengthTooShort { length, header_len });
+        }
+
+        let header = cursor.split_header_len(header_len)?;
+
+        Ok(GtpuHeader { header })
+    }
+
+    /// Like [`GtpuHeader::from_cursor`] for the payload of `udp`, whose destination port has
+    /// to be [`GTPU_UDP_PORT`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_udp(
+        udp: &UdpHeader<B>,
+        cursor: &mut PacketCursor<B>,
+    ) -> Result<Self, GtpuBpfCreationError> {
+        let port = udp.get_destination_port()?;
+        if port != GTPU_UDP_PORT {
+            return Err(GtpuBpfCreationError::WrongPort { port });
+        }
+        Self::from_cursor(cursor)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_version(&self) -> Result<u8, GtpuGetHeaderError> {
+        Ok(self.get_u8(FLAGS)? >> 5)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_flags(&self) -> Result<u8, GtpuGetHeaderError> {
+        self.get_u8(FLAGS)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_message_type(&self) -> Result<u8, GtpuGetHeaderError> {
+        self.get_u8(MESSAGE_TYPE)
+    }
+
+    /// The message type as [`GtpuMessageType`], unassigned values are returned as
+    /// [`GtpuMessageType::GtpuMessageTypeErrorVariant`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_typed_message_type(&self) -> Result<GtpuMessageType, GtpuGetHeaderError> {
+        Ok(GtpuMessageType::lookup_lenient(self.get_message_type()?))
+    }
+
+    /// Length of everything behind the first 8 bytes of the header: optional fields,
+    /// extension headers and T-PDU.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_length(&self) -> Result<u16, GtpuGetHeaderError> {
+        Ok(u16::from_be_bytes(
+            self.header
+                .get(LENGTH_START..LENGTH_END)
+                .ok_or(GtpuGetHeaderError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        ))
+    }
+
+    /// Length of the T-PDU behind the header.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_payload_len(&self) -> Result<usize, GtpuGetHeaderError> {
+        Ok(self.get_length()? as usize + GTPU_MIN_HEADER_SIZE - self.header.len())
+    }
+
+    /// Tunnel endpoint identifier.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_teid(&self) -> Result<u32, GtpuGetHeaderError> {
+        Ok(u32::from_be_bytes(
+            self.header
+                .get(TEID_START..TEID_END)
+                .ok_or(GtpuGetHeaderError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        ))
+    }
+
+    /// `None` if the sequence number flag isn't set.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_sequence_number(&self) -> Result<Option<u16>, GtpuGetHeaderError> {
+        if self.get_flags()? & FLAG_SEQUENCE_NUMBER == 0 {
+            return Ok(None);
+        }
+        Ok(Some(u16::from_be_bytes(
+            self.header
+                .get(SEQUENCE_NUMBER_START..SEQUENCE_NUMBER_END)
+                .ok_or(GtpuGetHeaderError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        )))
+    }
+
+    /// `None` if the N-PDU number flag isn't set.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_n_pdu_number(&self) -> Result<Option<u8>, GtpuGetHeaderError> {
+        if self.get_flags()? & FLAG_N_PDU_NUMBER == 0 {
+            return Ok(None);
+        }
+        Ok(Some(self.get_u8(N_PDU_NUMBER)?))
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn extension_headers(&self) -> GtpuExtensionHeadersIter<'_> {
+        // Headers without extension headers end at or before the next extension header type.
+        GtpuExtensionHeadersIter::with_parser(
+            self.header
+                .get(EXTENSION_HEADERS_START..)
+                .unwrap_or_default(),
+            GtpuExtensionHeaderParser::new(
+                self.get_u8(NEXT_EXTENSION_HEADER_TYPE).unwrap_or_default(),
+            ),
+        )
+    }
+
+    /// QoS flow identifier from the PDU session container extension header, `None` without
+    /// one.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_qfi(&self) -> Result<Option<u8>, GtpuGetHeaderError> {
+        for extension_header in self.extension_headers() {
+            let extension_header = extension_header?;
+            if extension_header.get_extension_type()
+                == GtpuExtensionHeaderType::PduSessionContainer as u8
+            {
+                // 4 bits PDU type and 4 bits spare, then 2 bits flags and 6 bits QFI for
+                // downlink and uplink.
+                let qfi = extension_header
+                    .get_content()
+                    .get(1)
+                    .ok_or(GtpuGetHeaderError::OutOfBoundsBufferAccess)?;
+                return Ok(Some(qfi & 0x3F));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Takes the inner IPv4 or IPv6 header of a G-PDU from `cursor`, which has to be left
+    /// behind the header by [`GtpuHeader::from_cursor`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn split_inner_ip(
+        &self,
+        cursor: &mut PacketCursor<B>,
+    ) -> Result<GtpuInnerIp<B>, GtpuInnerIpError> {
+        let message_type = self.get_message_type()?;
+        if message_type != GtpuMessageType::GPdu as u8 {
+            return Err(GtpuInnerIpError::NotGPdu { message_type });
+        }
+
+        let [version_byte] = *cursor.peek::<1>()?;
+        match version_byte >> 4 {
+            4 => Ok(GtpuInnerIp::Ipv4(Ipv4Header::from_cursor(cursor)?)),
+            6 => Ok(GtpuInnerIp::Ipv6(Ipv6Header::from_cursor(cursor)?)),
+            version => Err(GtpuInnerIpError::UnsupportedVersion { version }),
+        }
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn get_u8(&self, index: usize) -> Result<u8, GtpuGetHeaderError> {
+        self.header
+            .get(index)
+            .copied()
+            .ok_or(GtpuGetHeaderError::OutOfBoundsBufferAccess)
+    }
+}
+
+/// The user packet of a G-PDU.
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(PartialEq, Eq)]
+pub enum GtpuInnerIp<B> {
+    Ipv4(Ipv4Header<B>),
+    Ipv6(Ipv6Header<B>),
+}
+
+/// An extension header: its type, announced by the previous header, and its content without
+/// the length and next extension header type bytes.
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct GtpuExtensionHeader<'a> {
+    extension_type: u8,
+    content: &'a [u8],
+}
+
+impl<'a> GtpuExtensionHeader<'a> {
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_extension_type(&self) -> u8 {
+        self.extension_type
+    }
+
+    /// The extension type as [`GtpuExtensionHeaderType`], unassigned values are returned as
+    /// [`GtpuExtensionHeaderType::GtpuExtensionHeaderTypeErrorVariant`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_typed_extension_type(&self) -> GtpuExtensionHeaderType {
+        GtpuExtensionHeaderType::lookup_lenient(self.extension_type)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_content(&self) -> &'a [u8] {
+        self.content
+    }
+}
+
+/// Iterator over the extension headers of a GTP-U header. The chain was validated by
+/// [`GtpuHeader::from_cursor`], so it holds at most [`MAX_GTPU_EXTENSION_HEADERS`] headers.
+pub type GtpuExtensionHeadersIter<'a> = TlvIter<'a, GtpuExtensionHeaderParser>;
+
+/// Parses the extension headers of a [`GtpuExtensionHeadersIter`]. The type of an extension
+/// header is announced by the header in front of it, the parser carries it along.
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone)]
+pub struct GtpuExtensionHeaderParser {
+    next_type: u8,
+}
+
+impl GtpuExtensionHeaderParser {
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn new(next_type: u8) -> Self {
+        GtpuExtensionHeaderParser { next_type }
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn parse_extension_header<'a>(
+        &mut self,
+        extension_headers: &'a [u8],
+        offset: &mut usize,
+    ) -> Result<GtpuExtensionHeader<'a>, GtpuGetHeaderError> {
+        let extension_type = self.next_type;
+        let length = *extension_headers
+            .get(*offset)
+            .ok_or(GtpuGetHeaderError::OutOfBoundsBufferAccess)?;
+        let end = *offset + length as usize * GTPU_LENGTH_UNIT;
+        // The length byte and the next extension header type byte frame the content.
+        let content = extension_headers
+            .get(*offset + 1..end.saturating_sub(1))
+            .ok_or(GtpuGetHeaderError::OutOfBoundsBufferAccess)?;
+        self.next_type = *extension_headers
+            .get(end.wrapping_sub(1))
+            .ok_or(GtpuGetHeaderError::OutOfBoundsBufferAccess)?;
+
+        *offset = end;
+        Ok(GtpuExtensionHeader {
+            extension_type,
+            content,
+        })
+    }
+}
+
+impl<'a> TlvParser<'a> for GtpuExtensionHeaderParser {
+    type Item = GtpuExtensionHeader<'a>;
+    type Error = GtpuGetHeaderError;
+
+    const MAX: usize = MAX_GTPU_EXTENSION_HEADERS;
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn parse(
+        &mut self,
+        extension_headers: &'a [u8],
+        offset: &mut usize,
+    ) -> Option<Result<GtpuExtensionHeader<'a>, GtpuGetHeaderError>> {
+        if self.next_type == GtpuExtensionHeaderType::NoMoreExtensionHeaders as u8 {
+            return None;
+        }
+        Some(self.parse_extension_header(extension_headers, offset))
+    }
+}
+
+/// Walks the extension header chain of a header with the extension header flag set. Returns
+/// the length of the header including all extension headers.
+#[cfg_attr(feature = "inline", inline(always))]
+fn walk_extension_headers<B: PacketBuffer>(
+    cursor: &PacketCursor<B>,
+) -> Result<usize, GtpuBpfCreationError> {
+    let [next_type] = *cursor.peek_at::<1>(NEXT_EXTENSION_HEADER_TYPE)?;
+    let mut next_type = next_type;
+    let mut header_len = GTPU_OPTIONAL_HEADER_SIZE;
+
+    for _ in 0..MAX_GTPU_EXTENSION_HEADERS {
+        if next_type == GtpuExtensionHeaderType::NoMoreExtensionHeaders as u8 {
+            return Ok(header_len);
+        }
+
+        let [length] = *cursor.peek_at::<1>(header_len)?;
+        if length == 0 {
+            return Err(GtpuBpfCreationError::InvalidExtensionHeaderLength {
+                extension_type: next_type,
+            });
+        }
+        header_len += length as usize * GTPU_LENGTH_UNIT;
+        [next_type] = *cursor.peek_at::<1>(header_len - 1)?;
+    }
+
+    if next_type != GtpuExtensionHeaderType::NoMoreExtensionHeaders as u8 {
+        return Err(GtpuBpfCreationError::TooManyExtensionHeaders);
+    }
+    Ok(header_len)
+}
@@ -0,0 +1,276 @@
+use crate::cursor::CursorError;
+use crate::ipv4::Ipv4BpfCreationError;
+use crate::ipv6::Ipv6BpfCreationError;
+use crate::udp::UdpGetHeaderError;
+use core::array::TryFromSliceError;
+#[cfg(feature = "fmt")]
+use core::fmt::{Debug, Display, Formatter};
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum GtpuError {
+    BpfCreation(GtpuBpfCreationError),
+    GetHeader(GtpuGetHeaderError),
+    InnerIp(GtpuInnerIpError),
+}
+
+#[cfg(feature = "fmt")]
+impl Display for GtpuError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            GtpuError::BpfCreation(err) => {
+                write!(f, "{err}")
+            }
+            GtpuError::GetHeader(err) => {
+                write!(f, "{err}")
+            }
+            GtpuError::InnerIp(err) => {
+                write!(f, "{err}")
+            }
+        }
+    }
+}
+
+impl From<GtpuBpfCreationError> for GtpuError {
+    fn from(value: GtpuBpfCreationError) -> Self {
+        Self::BpfCreation(value)
+    }
+}
+
+impl From<GtpuGetHeaderError> for GtpuError {
+    fn from(value: GtpuGetHeaderError) -> Self {
+        Self::GetHeader(value)
+    }
+}
+
+impl From<GtpuInnerIpError> for GtpuError {
+    fn from(value: GtpuInnerIpError) -> Self {
+        Self::InnerIp(value)
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for GtpuError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum GtpuBpfCreationError {
+    WrongPort { port: u16 },
+    InvalidVersion { version: u8 },
+    GtpPrime,
+    LengthExceedsPayload { length: u16, size: usize },
+    LengthTooShort { length: u16, header_len: usize },
+    InvalidExtensionHeaderLength { extension_type: u8 },
+    TooManyExtensionHeaders,
+    HeaderTooShort { size: usize, expected: usize },
+    BpfOutOfBounds,
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for GtpuBpfCreationError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+impl From<CursorError> for GtpuBpfCreationError {
+    fn from(value: CursorError) -> Self {
+        match value {
+            CursorError::TooShort { size, expected } => Self::HeaderTooShort { size, expected },
+            CursorError::BpfOutOfBounds => Self::BpfOutOfBounds,
+            CursorError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            CursorError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+impl From<UdpGetHeaderError> for GtpuBpfCreationError {
+    fn from(value: UdpGetHeaderError) -> Self {
+        match value {
+            UdpGetHeaderError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            UdpGetHeaderError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for GtpuBpfCreationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::WrongPort { port } => {
+                write!(f, "UDP destination port expected to be 2152, was: {port}")
+            }
+            Self::InvalidVersion { version } => {
+                write!(f, "GTP-U version expected to be 1, was: {version}")
+            }
+            Self::GtpPrime => {
+                write!(f, "Protocol type flag not set, GTP' is not supported")
+            }
+            Self::LengthExceedsPayload { length, size } => {
+                write!(
+                    f,
+                    "GTP-U length {length} exceeds the remaining {size} bytes"
+                )
+            }
+            Self::LengthTooShort { length, header_len } => {
+                write!(
+                    f,
+                    "GTP-U length {length} doesn't cover the {header_len} bytes header"
+                )
+            }
+            Self::InvalidExtensionHeaderLength { extension_type } => {
+                write!(
+                    f,
+                    "GTP-U extension header {extension_type:#04X} has length 0"
+                )
+            }
+            Self::TooManyExtensionHeaders => {
+                write!(f, "Too many GTP-U extension headers")
+            }
+            Self::HeaderTooShort { size, expected } => {
+                write!(
+                    f,
+                    "GTP-U header expected to be at least {expected} bytes, was: {size}"
+                )
+            }
+            Self::BpfOutOfBounds => {
+                write!(f, "Bpf out of bounds")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for GtpuBpfCreationError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum GtpuGetHeaderError {
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for GtpuGetHeaderError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for GtpuGetHeaderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for GtpuGetHeaderError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum GtpuInnerIpError {
+    NotGPdu { message_type: u8 },
+    UnsupportedVersion { version: u8 },
+    Ipv4(Ipv4BpfCreationError),
+    Ipv6(Ipv6BpfCreationError),
+    PayloadTooShort { size: usize, expected: usize },
+    BpfOutOfBounds,
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for GtpuInnerIpError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+impl From<CursorError> for GtpuInnerIpError {
+    fn from(value: CursorError) -> Self {
+        match value {
+            CursorError::TooShort { size, expected } => Self::PayloadTooShort { size, expected },
+            CursorError::BpfOutOfBounds => Self::BpfOutOfBounds,
+            CursorError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            CursorError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+impl From<GtpuGetHeaderError> for GtpuInnerIpError {
+    fn from(value: GtpuGetHeaderError) -> Self {
+        match value {
+            GtpuGetHeaderError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            GtpuGetHeaderError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+impl From<Ipv4BpfCreationError> for GtpuInnerIpError {
+    fn from(value: Ipv4BpfCreationError) -> Self {
+        Self::Ipv4(value)
+    }
+}
+
+impl From<Ipv6BpfCreationError> for GtpuInnerIpError {
+    fn from(value: Ipv6BpfCreationError) -> Self {
+        Self::Ipv6(value)
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for GtpuInnerIpError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NotGPdu { message_type } => {
+                write!(
+                    f,
+                    "GTP-U message type expected to be G-PDU (255), was: {message_type}"
+                )
+            }
+            Self::UnsupportedVersion { version } => {
+                write!(
+                    f,
+                    "GTP-U payload expected to be IPv4 or IPv6, IP version was: {version}"
+                )
+            }
+            Self::Ipv4(err) => {
+                write!(f, "{err}")
+            }
+            Self::Ipv6(err) => {
+                write!(f, "{err}")
+            }
+            Self::PayloadTooShort { size, expected } => {
+                write!(
+                    f,
+                    "GTP-U payload expected to be at least {expected} bytes, was: {size}"
+                )
+            }
+            Self::BpfOutOfBounds => {
+                write!(f, "Bpf out of bounds")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for GtpuInnerIpError {}
@@ -0,0 +1,39 @@
+crate::macros::generate_matching_enum_impl! {
+    /// GTP-U message types, see 3GPP TS 29.281.
+    #[repr(u8)]
+    #[cfg_attr(feature = "fmt", derive(Debug))]
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub enum GtpuMessageType {
+        EchoRequest = 1,
+        EchoResponse = 2,
+        ErrorIndication = 26,
+        SupportedExtensionHeadersNotification = 31,
+        TunnelStatus = 253,
+        EndMarker = 254,
+        /// Carries a user packet (T-PDU)
+        GPdu = 255,
+    }
+    (error_value: 0x00),
+    (lookup_table: false)
+}
+
+crate::macros::generate_matching_enum_impl! {
+    /// GTP-U extension header types, see 3GPP TS 29.281.
+    #[repr(u8)]
+    #[cfg_attr(feature = "fmt", derive(Debug))]
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub enum GtpuExtensionHeaderType {
+        NoMoreExtensionHeaders = 0x00,
+        ServiceClassIndicator = 0x20,
+        UdpPort = 0x40,
+        RanContainer = 0x81,
+        LongPdcpPduNumber = 0x82,
+        XwRanContainer = 0x83,
+        NrRanContainer = 0x84,
+        /// Carries the QoS flow identifier (QFI) of 5G user plane traffic
+        PduSessionContainer = 0x85,
+        PdcpPduNumber = 0xC0,
+    }
+    (error_value: 0xFF),
+    (lookup_table: false)
+}
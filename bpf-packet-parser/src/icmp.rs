@@ -0,0 +1,227 @@
+mod error;
+mod header_values;
+
+pub use error::*;
+pub use header_values::*;
+
+use crate::buffer::PacketBuffer;
+use crate::cursor::PacketCursor;
+use crate::ipv4::Ipv4Header;
+#[cfg(feature = "fmt")]
+use core::fmt::Debug;
+
+// All ranges are [start..end).
+// This means start is included, end is not included.
+pub static TYPE: usize = 0;
+pub static CODE: usize = 1;
+pub static CHECKSUM_START: usize = 2;
+pub static CHECKSUM_END: usize = 4;
+pub static REST_OF_HEADER_START: usize = 4;
+pub static REST_OF_HEADER_END: usize = 8;
+pub static ECHO_IDENTIFIER_START: usize = 4;
+pub static ECHO_IDENTIFIER_END: usize = 6;
+pub static ECHO_SEQUENCE_NUMBER_START: usize = 6;
+pub static ECHO_SEQUENCE_NUMBER_END: usize = 8;
+pub static NEXT_HOP_MTU_START: usize = 6;
+pub static NEXT_HOP_MTU_END: usize = 8;
+pub static POINTER: usize = 4;
+
+pub static ICMP_HEADER_SIZE: usize = 8;
+
+/// ICMP header including the 4 type specific bytes, found in the payload of IPv4 packets with
+/// [`IpProtocol::Icmp`](crate::ip::IpProtocol::Icmp).
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(PartialEq, Eq)]
+pub struct IcmpHeader<B> {
+    // size of 8B
+    header: B,
+}
+
+impl<B: PacketBuffer> IcmpHeader<B> {
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn new_bpf(buf: B, end: usize) -> Result<(Self, B), IcmpBpfCreationError> {
+        let mut cursor = PacketCursor::new(buf, end);
+        let header = Self::from_cursor(&mut cursor)?;
+        Ok((header, cursor.into_inner()))
+    }
+
+    /// Takes the header from the front of `cursor` and leaves the cursor at the message data,
+    /// which is the quoted packet for error messages.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_cursor(cursor: &mut PacketCursor<B>) -> Result<Self, IcmpBpfCreationError> {
+        // We read the first 8 bytes of the message:
+        // 1 byte type
+        // 1 byte code
+        // 2 bytes checksum
+        // 4 bytes rest of header
+        // ------------------
+        // 8 bytes total
+
+        let header = cursor.split_header::<8>()?;
+
+        Ok(IcmpHeader { header })
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_type(&self) -> Result<u8, IcmpGetHeaderError> {
+        self.header
+            .get(TYPE)
+            .copied()
+            .ok_or(IcmpGetHeaderError::OutOfBoundsBufferAccess)
+    }
+
+    /// The type as [`IcmpType`], unassigned types are returned as
+    /// [`IcmpType::IcmpTypeErrorVariant`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_typed_type(&self) -> Result<IcmpType, IcmpGetHeaderError> {
+        Ok(IcmpType::lookup_lenient(self.get_type()?))
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_code(&self) -> Result<u8, IcmpGetHeaderError> {
+        self.header
+            .get(CODE)
+            .copied()
+            .ok_or(IcmpGetHeaderError::OutOfBoundsBufferAccess)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_checksum(&self) -> Result<u16, IcmpGetHeaderError> {
+        self.get_u16(CHECKSUM_START, CHECKSUM_END)
+    }
+
+    /// The 4 type specific bytes behind the checksum.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_rest_of_header(&self) -> Result<&[u8; 4], IcmpGetHeaderError> {
+        Ok(self
+            .header
+            .get(REST_OF_HEADER_START..REST_OF_HEADER_END)
+            .ok_or(IcmpGetHeaderError::OutOfBoundsBufferAccess)?
+            .try_into()?)
+    }
+
+    /// Destination unreachable, source quench, redirect, time exceeded and parameter problem
+    /// messages quote the packet which caused them.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn is_error_message(&self) -> Result<bool, IcmpGetHeaderError> {
+        Ok(matches!(
+            self.get_typed_type()?,
+            IcmpType::DestinationUnreachable
+                | IcmpType::SourceQuench
+                | IcmpType::Redirect
+                | IcmpType::TimeExceeded
+                | IcmpType::ParameterProblem
+        ))
+    }
+
+    /// Identifier of echo request and reply messages.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_echo_identifier(&self) -> Result<u16, IcmpGetHeaderError> {
+        self.check_echo()?;
+        self.get_u16(ECHO_IDENTIFIER_START, ECHO_IDENTIFIER_END)
+    }
+
+    /// Sequence number of echo request and reply messages.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_echo_sequence_number(&self) -> Result<u16, IcmpGetHeaderError> {
+        self.check_echo()?;
+        self.get_u16(ECHO_SEQUENCE_NUMBER_START, ECHO_SEQUENCE_NUMBER_END)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_destination_unreachable_code(
+        &self,
+    ) -> Result<DestinationUnreachableCode, IcmpGetHeaderError> {
+        self.check_type(IcmpType::DestinationUnreachable)?;
+        Ok(DestinationUnreachableCode::lookup_lenient(self.get_code()?))
+    }
+
+    /// MTU of the next hop of [`DestinationUnreachableCode::FragmentationNeeded`] messages.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_next_hop_mtu(&self) -> Result<u16, IcmpGetHeaderError> {
+        self.check_type(IcmpType::DestinationUnreachable)?;
+        self.get_u16(NEXT_HOP_MTU_START, NEXT_HOP_MTU_END)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_redirect_code(&self) -> Result<RedirectCode, IcmpGetHeaderError> {
+        self.check_type(IcmpType::Redirect)?;
+        Ok(RedirectCode::lookup_lenient(self.get_code()?))
+    }
+
+    /// Address of the gateway to redirect to.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_redirect_gateway(&self) -> Result<&[u8; 4], IcmpGetHeaderError> {
+        self.check_type(IcmpType::Redirect)?;
+        self.get_rest_of_header()
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_time_exceeded_code(&self) -> Result<TimeExceededCode, IcmpGetHeaderError> {
+        self.check_type(IcmpType::TimeExceeded)?;
+        Ok(TimeExceededCode::lookup_lenient(self.get_code()?))
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_parameter_problem_code(&self) -> Result<ParameterProblemCode, IcmpGetHeaderError> {
+        self.check_type(IcmpType::ParameterProblem)?;
+        Ok(ParameterProblemCode::lookup_lenient(self.get_code()?))
+    }
+
+    /// Offset of the erroneous byte in the quoted packet of parameter problem messages.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_pointer(&self) -> Result<u8, IcmpGetHeaderError> {
+        self.check_type(IcmpType::ParameterProblem)?;
+        self.header
+            .get(POINTER)
+            .copied()
+            .ok_or(IcmpGetHeaderError::OutOfBoundsBufferAccess)
+    }
+
+    /// Takes the quoted IPv4 header of an error message from the front of `cursor`, which has
+    /// to be the message data of this header. The cursor is left at the quoted first 8 bytes
+    /// of the original payload.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn split_quoted_ipv4(
+        &self,
+        cursor: &mut PacketCursor<B>,
+    ) -> Result<Ipv4Header<B>, IcmpQuotedHeaderError> {
+        if !self.is_error_message()? {
+            return Err(IcmpQuotedHeaderError::NotErrorMessage {
+                icmp_type: self.get_type()?,
+            });
+        }
+        Ok(Ipv4Header::from_cursor(cursor)?)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn check_echo(&self) -> Result<(), IcmpGetHeaderError> {
+        let icmp_type = self.get_typed_type()?;
+        if icmp_type != IcmpType::EchoRequest && icmp_type != IcmpType::EchoReply {
+            return Err(IcmpGetHeaderError::WrongMessageType {
+                icmp_type: self.get_type()?,
+            });
+        }
+        Ok(())
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn check_type(&self, expected: IcmpType) -> Result<(), IcmpGetHeaderError> {
+        if self.get_typed_type()? != expected {
+            return Err(IcmpGetHeaderError::WrongMessageType {
+                icmp_type: self.get_type()?,
+            });
+        }
+        Ok(())
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn get_u16(&self, start: usize, end: usize) -> Result<u16, IcmpGetHeaderError> {
+        Ok(u16::from_be_bytes(
+            self.header
+                .get(start..end)
+                .ok_or(IcmpGetHeaderError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        ))
+    }
+}
@@ -0,0 +1,181 @@
+use crate::cursor::CursorError;
+use crate::ipv4::Ipv4BpfCreationError;
+use core::array::TryFromSliceError;
+#[cfg(feature = "fmt")]
+use core::fmt::{Debug, Display, Formatter};
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum IcmpError {
+    BpfCreation(IcmpBpfCreationError),
+    GetHeader(IcmpGetHeaderError),
+    QuotedHeader(IcmpQuotedHeaderError),
+}
+
+#[cfg(feature = "fmt")]
+impl Display for IcmpError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            IcmpError::BpfCreation(err) => {
+                write!(f, "{err}")
+            }
+            IcmpError::GetHeader(err) => {
+                write!(f, "{err}")
+            }
+            IcmpError::QuotedHeader(err) => {
+                write!(f, "{err}")
+            }
+        }
+    }
+}
+
+impl From<IcmpBpfCreationError> for IcmpError {
+    fn from(value: IcmpBpfCreationError) -> Self {
+        Self::BpfCreation(value)
+    }
+}
+
+impl From<IcmpGetHeaderError> for IcmpError {
+    fn from(value: IcmpGetHeaderError) -> Self {
+        Self::GetHeader(value)
+    }
+}
+
+impl From<IcmpQuotedHeaderError> for IcmpError {
+    fn from(value: IcmpQuotedHeaderError) -> Self {
+        Self::QuotedHeader(value)
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for IcmpError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum IcmpBpfCreationError {
+    MessageTooShort { size: usize, expected: usize },
+    BpfOutOfBounds,
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<CursorError> for IcmpBpfCreationError {
+    fn from(value: CursorError) -> Self {
+        match value {
+            CursorError::TooShort { size, expected } => Self::MessageTooShort { size, expected },
+            CursorError::BpfOutOfBounds => Self::BpfOutOfBounds,
+            CursorError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            CursorError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for IcmpBpfCreationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::MessageTooShort { size, expected } => {
+                write!(
+                    f,
+                    "ICMP message expected to be at least {expected} bytes, was: {size}"
+                )
+            }
+            Self::BpfOutOfBounds => {
+                write!(f, "Bpf out of bounds")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for IcmpBpfCreationError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum IcmpGetHeaderError {
+    WrongMessageType { icmp_type: u8 },
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for IcmpGetHeaderError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for IcmpGetHeaderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::WrongMessageType { icmp_type } => {
+                write!(f, "Field not present in ICMP messages of type {icmp_type}")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for IcmpGetHeaderError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum IcmpQuotedHeaderError {
+    NotErrorMessage { icmp_type: u8 },
+    Ipv4(Ipv4BpfCreationError),
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<Ipv4BpfCreationError> for IcmpQuotedHeaderError {
+    fn from(value: Ipv4BpfCreationError) -> Self {
+        Self::Ipv4(value)
+    }
+}
+
+impl From<IcmpGetHeaderError> for IcmpQuotedHeaderError {
+    fn from(value: IcmpGetHeaderError) -> Self {
+        match value {
+            IcmpGetHeaderError::WrongMessageType { icmp_type } => {
+                Self::NotErrorMessage { icmp_type }
+            }
+            IcmpGetHeaderError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            IcmpGetHeaderError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for IcmpQuotedHeaderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NotErrorMessage { icmp_type } => {
+                write!(f, "ICMP messages of type {icmp_type} don't quote a packet")
+            }
+            Self::Ipv4(err) => {
+                write!(f, "Quoted packet: {err}")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for IcmpQuotedHeaderError {}
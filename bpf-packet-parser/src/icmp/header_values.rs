@@ -0,0 +1,109 @@
+crate::macros::generate_matching_enum_impl! {
+    /// Sources:
+    /// `<https://www.iana.org/assignments/icmp-parameters/icmp-parameters.xhtml>`
+    #[repr(u8)]
+    #[cfg_attr(feature = "fmt", derive(Debug))]
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub enum IcmpType {
+        EchoReply = 0,
+        DestinationUnreachable = 3,
+        /// Deprecated
+        SourceQuench = 4,
+        Redirect = 5,
+        EchoRequest = 8,
+        RouterAdvertisement = 9,
+        RouterSolicitation = 10,
+        TimeExceeded = 11,
+        ParameterProblem = 12,
+        Timestamp = 13,
+        TimestampReply = 14,
+        /// Deprecated
+        InformationRequest = 15,
+        /// Deprecated
+        InformationReply = 16,
+        /// Deprecated
+        AddressMaskRequest = 17,
+        /// Deprecated
+        AddressMaskReply = 18,
+        /// Deprecated
+        Traceroute = 30,
+        ExtendedEchoRequest = 42,
+        ExtendedEchoReply = 43,
+        /// RFC 3692 style experiments
+        Experimental = 253..=254,
+    }
+    (error_value: 0xFF),
+    (lookup_table: true)
+}
+
+crate::macros::generate_matching_enum_impl! {
+    /// Codes of [`IcmpType::DestinationUnreachable`].
+    #[repr(u8)]
+    #[cfg_attr(feature = "fmt", derive(Debug))]
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub enum DestinationUnreachableCode {
+        NetUnreachable = 0,
+        HostUnreachable = 1,
+        ProtocolUnreachable = 2,
+        PortUnreachable = 3,
+        /// Fragmentation needed and don't fragment was set, the MTU of the next hop is in the
+        /// rest of the header.
+        FragmentationNeeded = 4,
+        SourceRouteFailed = 5,
+        DestinationNetworkUnknown = 6,
+        DestinationHostUnknown = 7,
+        SourceHostIsolated = 8,
+        NetworkAdministrativelyProhibited = 9,
+        HostAdministrativelyProhibited = 10,
+        NetworkUnreachableForTos = 11,
+        HostUnreachableForTos = 12,
+        CommunicationAdministrativelyProhibited = 13,
+        HostPrecedenceViolation = 14,
+        PrecedenceCutoffInEffect = 15,
+    }
+    (error_value: 0xFF),
+    (lookup_table: false)
+}
+
+crate::macros::generate_matching_enum_impl! {
+    /// Codes of [`IcmpType::Redirect`].
+    #[repr(u8)]
+    #[cfg_attr(feature = "fmt", derive(Debug))]
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub enum RedirectCode {
+        Network = 0,
+        Host = 1,
+        TosAndNetwork = 2,
+        TosAndHost = 3,
+    }
+    (error_value: 0xFF),
+    (lookup_table: false)
+}
+
+crate::macros::generate_matching_enum_impl! {
+    /// Codes of [`IcmpType::TimeExceeded`].
+    #[repr(u8)]
+    #[cfg_attr(feature = "fmt", derive(Debug))]
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub enum TimeExceededCode {
+        TtlExceededInTransit = 0,
+        FragmentReassemblyTimeExceeded = 1,
+    }
+    (error_value: 0xFF),
+    (lookup_table: false)
+}
+
+crate::macros::generate_matching_enum_impl! {
+    /// Codes of [`IcmpType::ParameterProblem`].
+    #[repr(u8)]
+    #[cfg_attr(feature = "fmt", derive(Debug))]
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub enum ParameterProblemCode {
+        /// The pointer in the rest of the header indicates the error.
+        PointerIndicatesError = 0,
+        MissingRequiredOption = 1,
+        BadLength = 2,
+    }
+    (error_value: 0xFF),
+    (lookup_table: false)
+}
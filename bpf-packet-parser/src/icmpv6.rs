@@ -0,0 +1,219 @@
+mod error;
+mod header_values;
+mod ndp;
+
+pub use error::*;
+pub use header_values::*;
+pub use ndp::*;
+
+use crate::buffer::PacketBuffer;
+use crate::cursor::PacketCursor;
+use crate::ipv6::Ipv6Header;
+#[cfg(feature = "fmt")]
+use core::fmt::Debug;
+
+// All ranges are [start..end).
+// This means start is included, end is not included.
+pub static TYPE: usize = 0;
+pub static CODE: usize = 1;
+pub static CHECKSUM_START: usize = 2;
+pub static CHECKSUM_END: usize = 4;
+pub static REST_OF_HEADER_START: usize = 4;
+pub static REST_OF_HEADER_END: usize = 8;
+pub static ECHO_IDENTIFIER_START: usize = 4;
+pub static ECHO_IDENTIFIER_END: usize = 6;
+pub static ECHO_SEQUENCE_NUMBER_START: usize = 6;
+pub static ECHO_SEQUENCE_NUMBER_END: usize = 8;
+pub static MTU_START: usize = 4;
+pub static MTU_END: usize = 8;
+pub static POINTER_START: usize = 4;
+pub static POINTER_END: usize = 8;
+
+pub static ICMPV6_HEADER_SIZE: usize = 8;
+
+/// ICMPv6 header including the 4 type specific bytes, found behind IPv6 headers with
+/// [`IpProtocol::IcmpV6`](crate::ip::IpProtocol::IcmpV6).
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(PartialEq, Eq)]
+pub struct Icmpv6Header<B> {
+    // size of 8B
+    header: B,
+}
+
+impl<B: PacketBuffer> Icmpv6Header<B> {
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn new_bpf(buf: B, end: usize) -> Result<(Self, B), Icmpv6BpfCreationError> {
+        let mut cursor = PacketCursor::new(buf, end);
+        let header = Self::from_cursor(&mut cursor)?;
+        Ok((header, cursor.into_inner()))
+    }
+
+    /// Takes the header from the front of `cursor` and leaves the cursor at the message body,
+    /// which is the quoted packet for error messages.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_cursor(cursor: &mut PacketCursor<B>) -> Result<Self, Icmpv6BpfCreationError> {
+        // We read the first 8 bytes of the message:
+        // 1 byte type
+        // 1 byte code
+        // 2 bytes checksum
+        // 4 bytes rest of header
+        // ------------------
+        // 8 bytes total
+
+        let header = cursor.split_header::<8>()?;
+
+        Ok(Icmpv6Header { header })
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_type(&self) -> Result<u8, Icmpv6GetHeaderError> {
+        self.header
+            .get(TYPE)
+            .copied()
+            .ok_or(Icmpv6GetHeaderError::OutOfBoundsBufferAccess)
+    }
+
+    /// The type as [`Icmpv6Type`], unassigned types are returned as
+    /// [`Icmpv6Type::Icmpv6TypeErrorVariant`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_typed_type(&self) -> Result<Icmpv6Type, Icmpv6GetHeaderError> {
+        Ok(Icmpv6Type::lookup_lenient(self.get_type()?))
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_code(&self) -> Result<u8, Icmpv6GetHeaderError> {
+        self.header
+            .get(CODE)
+            .copied()
+            .ok_or(Icmpv6GetHeaderError::OutOfBoundsBufferAccess)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_checksum(&self) -> Result<u16, Icmpv6GetHeaderError> {
+        self.get_u16(CHECKSUM_START, CHECKSUM_END)
+    }
+
+    /// The 4 type specific bytes behind the checksum.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_rest_of_header(&self) -> Result<&[u8; 4], Icmpv6GetHeaderError> {
+        Ok(self
+            .header
+            .get(REST_OF_HEADER_START..REST_OF_HEADER_END)
+            .ok_or(Icmpv6GetHeaderError::OutOfBoundsBufferAccess)?
+            .try_into()?)
+    }
+
+    /// Types below 128 are error messages, they quote the packet which caused them.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn is_error_message(&self) -> Result<bool, Icmpv6GetHeaderError> {
+        Ok(self.get_type()? < 128)
+    }
+
+    /// Identifier of echo request and reply messages.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_echo_identifier(&self) -> Result<u16, Icmpv6GetHeaderError> {
+        self.check_echo()?;
+        self.get_u16(ECHO_IDENTIFIER_START, ECHO_IDENTIFIER_END)
+    }
+
+    /// Sequence number of echo request and reply messages.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_echo_sequence_number(&self) -> Result<u16, Icmpv6GetHeaderError> {
+        self.check_echo()?;
+        self.get_u16(ECHO_SEQUENCE_NUMBER_START, ECHO_SEQUENCE_NUMBER_END)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_destination_unreachable_code(
+        &self,
+    ) -> Result<Icmpv6DestinationUnreachableCode, Icmpv6GetHeaderError> {
+        self.check_type(Icmpv6Type::DestinationUnreachable)?;
+        Ok(Icmpv6DestinationUnreachableCode::lookup_lenient(
+            self.get_code()?,
+        ))
+    }
+
+    /// MTU of the next hop of packet too big messages.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_mtu(&self) -> Result<u32, Icmpv6GetHeaderError> {
+        self.check_type(Icmpv6Type::PacketTooBig)?;
+        self.get_u32(MTU_START, MTU_END)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_time_exceeded_code(&self) -> Result<Icmpv6TimeExceededCode, Icmpv6GetHeaderError> {
+        self.check_type(Icmpv6Type::TimeExceeded)?;
+        Ok(Icmpv6TimeExceededCode::lookup_lenient(self.get_code()?))
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_parameter_problem_code(
+        &self,
+    ) -> Result<Icmpv6ParameterProblemCode, Icmpv6GetHeaderError> {
+        self.check_type(Icmpv6Type::ParameterProblem)?;
+        Ok(Icmpv6ParameterProblemCode::lookup_lenient(self.get_code()?))
+    }
+
+    /// Offset of the erroneous byte in the quoted packet of parameter problem messages.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_pointer(&self) -> Result<u32, Icmpv6GetHeaderError> {
+        self.check_type(Icmpv6Type::ParameterProblem)?;
+        self.get_u32(POINTER_START, POINTER_END)
+    }
+
+    /// Takes the quoted IPv6 header of an error message from the front of `cursor`, which has
+    /// to be the message body of this header. The cursor is left at the quoted payload.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn split_quoted_ipv6(
+        &self,
+        cursor: &mut PacketCursor<B>,
+    ) -> Result<Ipv6Header<B>, Icmpv6QuotedHeaderError> {
+        if !self.is_error_message()? {
+            return Err(Icmpv6QuotedHeaderError::NotErrorMessage {
+                icmp_type: self.get_type()?,
+            });
+        }
+        Ok(Ipv6Header::from_cursor(cursor)?)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn check_echo(&self) -> Result<(), Icmpv6GetHeaderError> {
+        let icmp_type = self.get_typed_type()?;
+        if icmp_type != Icmpv6Type::EchoRequest && icmp_type != Icmpv6Type::EchoReply {
+            return Err(Icmpv6GetHeaderError::WrongMessageType {
+                icmp_type: self.get_type()?,
+            });
+        }
+        Ok(())
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn check_type(&self, expected: Icmpv6Type) -> Result<(), Icmpv6GetHeaderError> {
+        if self.get_typed_type()? != expected {
+            return Err(Icmpv6GetHeaderError::WrongMessageType {
+                icmp_type: self.get_type()?,
+            });
+        }
+        Ok(())
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn get_u16(&self, start: usize, end: usize) -> Result<u16, Icmpv6GetHeaderError> {
+        Ok(u16::from_be_bytes(
+            self.header
+                .get(start..end)
+                .ok_or(Icmpv6GetHeaderError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        ))
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn get_u32(&self, start: usize, end: usize) -> Result<u32, Icmpv6GetHeaderError> {
+        Ok(u32::from_be_bytes(
+            self.header
+                .get(start..end)
+                .ok_or(Icmpv6GetHeaderError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        ))
+    }
+}
@@ -0,0 +1,326 @@
+use crate::cursor::CursorError;
+use crate::ipv6::Ipv6BpfCreationError;
+use core::array::TryFromSliceError;
+#[cfg(feature = "fmt")]
+use core::fmt::{Debug, Display, Formatter};
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum Icmpv6Error {
+    BpfCreation(Icmpv6BpfCreationError),
+    GetHeader(Icmpv6GetHeaderError),
+    QuotedHeader(Icmpv6QuotedHeaderError),
+    Ndp(NdpError),
+    NdpOption(NdpOptionError),
+}
+
+#[cfg(feature = "fmt")]
+impl Display for Icmpv6Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Icmpv6Error::BpfCreation(err) => {
+                write!(f, "{err}")
+            }
+            Icmpv6Error::GetHeader(err) => {
+                write!(f, "{err}")
+            }
+            Icmpv6Error::QuotedHeader(err) => {
+                write!(f, "{err}")
+            }
+            Icmpv6Error::Ndp(err) => {
+                write!(f, "{err}")
+            }
+            Icmpv6Error::NdpOption(err) => {
+                write!(f, "{err}")
+            }
+        }
+    }
+}
+
+impl From<Icmpv6BpfCreationError> for Icmpv6Error {
+    fn from(value: Icmpv6BpfCreationError) -> Self {
+        Self::BpfCreation(value)
+    }
+}
+
+impl From<Icmpv6GetHeaderError> for Icmpv6Error {
+    fn from(value: Icmpv6GetHeaderError) -> Self {
+        Self::GetHeader(value)
+    }
+}
+
+impl From<Icmpv6QuotedHeaderError> for Icmpv6Error {
+    fn from(value: Icmpv6QuotedHeaderError) -> Self {
+        Self::QuotedHeader(value)
+    }
+}
+
+impl From<NdpError> for Icmpv6Error {
+    fn from(value: NdpError) -> Self {
+        Self::Ndp(value)
+    }
+}
+
+impl From<NdpOptionError> for Icmpv6Error {
+    fn from(value: NdpOptionError) -> Self {
+        Self::NdpOption(value)
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for Icmpv6Error {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum Icmpv6BpfCreationError {
+    MessageTooShort { size: usize, expected: usize },
+    BpfOutOfBounds,
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<CursorError> for Icmpv6BpfCreationError {
+    fn from(value: CursorError) -> Self {
+        match value {
+            CursorError::TooShort { size, expected } => Self::MessageTooShort { size, expected },
+            CursorError::BpfOutOfBounds => Self::BpfOutOfBounds,
+            CursorError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            CursorError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for Icmpv6BpfCreationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::MessageTooShort { size, expected } => {
+                write!(
+                    f,
+                    "ICMPv6 message expected to be at least {expected} bytes, was: {size}"
+                )
+            }
+            Self::BpfOutOfBounds => {
+                write!(f, "Bpf out of bounds")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for Icmpv6BpfCreationError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum Icmpv6GetHeaderError {
+    WrongMessageType { icmp_type: u8 },
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for Icmpv6GetHeaderError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for Icmpv6GetHeaderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::WrongMessageType { icmp_type } => {
+                write!(
+                    f,
+                    "Field not present in ICMPv6 messages of type {icmp_type}"
+                )
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for Icmpv6GetHeaderError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum Icmpv6QuotedHeaderError {
+    NotErrorMessage { icmp_type: u8 },
+    Ipv6(Ipv6BpfCreationError),
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<Ipv6BpfCreationError> for Icmpv6QuotedHeaderError {
+    fn from(value: Ipv6BpfCreationError) -> Self {
+        Self::Ipv6(value)
+    }
+}
+
+impl From<Icmpv6GetHeaderError> for Icmpv6QuotedHeaderError {
+    fn from(value: Icmpv6GetHeaderError) -> Self {
+        match value {
+            Icmpv6GetHeaderError::WrongMessageType { icmp_type } => {
+                Self::NotErrorMessage { icmp_type }
+            }
+            Icmpv6GetHeaderError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            Icmpv6GetHeaderError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for Icmpv6QuotedHeaderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NotErrorMessage { icmp_type } => {
+                write!(
+                    f,
+                    "ICMPv6 messages of type {icmp_type} don't quote a packet"
+                )
+            }
+            Self::Ipv6(err) => {
+                write!(f, "Quoted packet: {err}")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for Icmpv6QuotedHeaderError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum NdpError {
+    WrongMessageType { icmp_type: u8 },
+    InvalidCode { code: u8 },
+    MessageTooShort { size: usize, expected: usize },
+    BpfOutOfBounds,
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for NdpError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+impl From<CursorError> for NdpError {
+    fn from(value: CursorError) -> Self {
+        match value {
+            CursorError::TooShort { size, expected } => Self::MessageTooShort { size, expected },
+            CursorError::BpfOutOfBounds => Self::BpfOutOfBounds,
+            CursorError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            CursorError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+impl From<Icmpv6GetHeaderError> for NdpError {
+    fn from(value: Icmpv6GetHeaderError) -> Self {
+        match value {
+            Icmpv6GetHeaderError::WrongMessageType { icmp_type } => {
+                Self::WrongMessageType { icmp_type }
+            }
+            Icmpv6GetHeaderError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            Icmpv6GetHeaderError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for NdpError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::WrongMessageType { icmp_type } => {
+                write!(f, "ICMPv6 message of type {icmp_type} is not the expected Neighbor Discovery message")
+            }
+            Self::InvalidCode { code } => {
+                write!(f, "Neighbor Discovery message expected code 0, was: {code}")
+            }
+            Self::MessageTooShort { size, expected } => {
+                write!(
+                    f,
+                    "Neighbor Discovery message body expected to be at least {expected} bytes, was: {size}"
+                )
+            }
+            Self::BpfOutOfBounds => {
+                write!(f, "Bpf out of bounds")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for NdpError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum NdpOptionError {
+    InvalidLength { option_type: u8, length: u8 },
+    Truncated { option_type: u8 },
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for NdpOptionError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for NdpOptionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidLength {
+                option_type,
+                length,
+            } => {
+                write!(
+                    f,
+                    "Neighbor Discovery option {option_type} has invalid length: {length}"
+                )
+            }
+            Self::Truncated { option_type } => {
+                write!(
+                    f,
+                    "Neighbor Discovery option {option_type} exceeds the message"
+                )
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for NdpOptionError {}
@@ -0,0 +1,130 @@
+crate::macros::generate_matching_enum_impl! {
+    /// Sources:
+    /// `<https://www.iana.org/assignments/icmpv6-parameters/icmpv6-parameters.xhtml>`
+    #[repr(u8)]
+    #[cfg_attr(feature = "fmt", derive(Debug))]
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub enum Icmpv6Type {
+        DestinationUnreachable = 1,
+        PacketTooBig = 2,
+        TimeExceeded = 3,
+        ParameterProblem = 4,
+        /// Private experimentation with error messages
+        PrivateExperimentationError = 100..=101,
+        EchoRequest = 128,
+        EchoReply = 129,
+        MulticastListenerQuery = 130,
+        MulticastListenerReport = 131,
+        MulticastListenerDone = 132,
+        RouterSolicitation = 133,
+        RouterAdvertisement = 134,
+        NeighborSolicitation = 135,
+        NeighborAdvertisement = 136,
+        Redirect = 137,
+        RouterRenumbering = 138,
+        NodeInformationQuery = 139,
+        NodeInformationResponse = 140,
+        InverseNeighborDiscoverySolicitation = 141,
+        InverseNeighborDiscoveryAdvertisement = 142,
+        MulticastListenerReportV2 = 143,
+        HomeAgentAddressDiscoveryRequest = 144,
+        HomeAgentAddressDiscoveryReply = 145,
+        MobilePrefixSolicitation = 146,
+        MobilePrefixAdvertisement = 147,
+        CertificationPathSolicitation = 148,
+        CertificationPathAdvertisement = 149,
+        ExperimentalMobility = 150,
+        MulticastRouterAdvertisement = 151,
+        MulticastRouterSolicitation = 152,
+        MulticastRouterTermination = 153,
+        Fmipv6 = 154,
+        RplControl = 155,
+        Ilnpv6LocatorUpdate = 156,
+        DuplicateAddressRequest = 157,
+        DuplicateAddressConfirmation = 158,
+        MplControl = 159,
+        ExtendedEchoRequest = 160,
+        ExtendedEchoReply = 161,
+        /// Private experimentation with informational messages
+        PrivateExperimentationInformational = 200..=201,
+    }
+    (error_value: 0xFF),
+    (lookup_table: true)
+}
+
+crate::macros::generate_matching_enum_impl! {
+    /// Codes of [`Icmpv6Type::DestinationUnreachable`].
+    #[repr(u8)]
+    #[cfg_attr(feature = "fmt", derive(Debug))]
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub enum Icmpv6DestinationUnreachableCode {
+        NoRouteToDestination = 0,
+        AdministrativelyProhibited = 1,
+        BeyondScopeOfSourceAddress = 2,
+        AddressUnreachable = 3,
+        PortUnreachable = 4,
+        SourceAddressFailedPolicy = 5,
+        RejectRouteToDestination = 6,
+        SourceRoutingHeaderError = 7,
+        HeadersTooLong = 8,
+    }
+    (error_value: 0xFF),
+    (lookup_table: false)
+}
+
+crate::macros::generate_matching_enum_impl! {
+    /// Codes of [`Icmpv6Type::TimeExceeded`].
+    #[repr(u8)]
+    #[cfg_attr(feature = "fmt", derive(Debug))]
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub enum Icmpv6TimeExceededCode {
+        HopLimitExceededInTransit = 0,
+        FragmentReassemblyTimeExceeded = 1,
+    }
+    (error_value: 0xFF),
+    (lookup_table: false)
+}
+
+crate::macros::generate_matching_enum_impl! {
+    /// Codes of [`Icmpv6Type::ParameterProblem`].
+    #[repr(u8)]
+    #[cfg_attr(feature = "fmt", derive(Debug))]
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub enum Icmpv6ParameterProblemCode {
+        ErroneousHeaderField = 0,
+        UnrecognizedNextHeader = 1,
+        UnrecognizedIpv6Option = 2,
+        IncompleteHeaderChain = 3,
+        SrUpperLayerHeaderError = 4,
+        UnrecognizedNextHeaderByIntermediateNode = 5,
+        ExtensionHeaderTooBig = 6,
+        ExtensionHeaderChainTooLong = 7,
+        TooManyExtensionHeaders = 8,
+        TooManyOptions = 9,
+        OptionTooBig = 10,
+    }
+    (error_value: 0xFF),
+    (lookup_table: false)
+}
+
+crate::macros::generate_matching_enum_impl! {
+    /// Types of Neighbor Discovery options.
+    #[repr(u8)]
+    #[cfg_attr(feature = "fmt", derive(Debug))]
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub enum NdpOptionType {
+        SourceLinkLayerAddress = 1,
+        TargetLinkLayerAddress = 2,
+        PrefixInformation = 3,
+        RedirectedHeader = 4,
+        Mtu = 5,
+        Nonce = 14,
+        RouteInformation = 24,
+        RecursiveDnsServer = 25,
+        DnsSearchList = 31,
+        CaptivePortal = 37,
+        Pref64 = 38,
+    }
+    (error_value: 0x00),
+    (lookup_table: false)
+}
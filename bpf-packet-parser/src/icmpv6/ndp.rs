@@ -0,0 +1,471 @@
+use crate::buffer::PacketBuffer;
+use crate::cursor::PacketCursor;
+use crate::icmpv6::{Icmpv6Header, Icmpv6Type, NdpError, NdpOptionError, NdpOptionType};
+use crate::tlv::{TlvIter, TlvParser};
+#[cfg(feature = "fmt")]
+use core::fmt::Debug;
+
+// All ranges are [start..end).
+// This means start is included, end is not included.
+// Offsets into the rest of the ICMPv6 header.
+pub static RA_CUR_HOP_LIMIT: usize = 0;
+pub static RA_FLAGS: usize = 1;
+pub static RA_ROUTER_LIFETIME_START: usize = 2;
+pub static RA_ROUTER_LIFETIME_END: usize = 4;
+pub static NA_FLAGS: usize = 0;
+// Offsets into the message body.
+pub static RA_REACHABLE_TIME_START: usize = 0;
+pub static RA_REACHABLE_TIME_END: usize = 4;
+pub static RA_RETRANS_TIMER_START: usize = 4;
+pub static RA_RETRANS_TIMER_END: usize = 8;
+pub static TARGET_ADDRESS_START: usize = 0;
+pub static TARGET_ADDRESS_END: usize = 16;
+// Offsets into the data of a prefix information option.
+pub static PREFIX_LENGTH: usize = 0;
+pub static PREFIX_FLAGS: usize = 1;
+pub static PREFIX_VALID_LIFETIME_START: usize = 2;
+pub static PREFIX_VALID_LIFETIME_END: usize = 6;
+pub static PREFIX_PREFERRED_LIFETIME_START: usize = 6;
+pub static PREFIX_PREFERRED_LIFETIME_END: usize = 10;
+pub static PREFIX_START: usize = 14;
+pub static PREFIX_END: usize = 30;
+
+pub static RA_FLAG_MANAGED: u8 = 0x80;
+pub static RA_FLAG_OTHER: u8 = 0x40;
+pub static NA_FLAG_ROUTER: u8 = 0x80;
+pub static NA_FLAG_SOLICITED: u8 = 0x40;
+pub static NA_FLAG_OVERRIDE: u8 = 0x20;
+pub static PREFIX_FLAG_ON_LINK: u8 = 0x80;
+pub static PREFIX_FLAG_AUTONOMOUS: u8 = 0x40;
+
+/// Option lengths are in units of 8 bytes.
+pub static NDP_OPTION_LENGTH_UNIT: usize = 8;
+
+/// Maximum number of options the iterator returns.
+pub const MAX_NDP_OPTIONS: usize = 16;
+
+/// Router solicitation message, the options follow the ICMPv6 header directly.
+///
+/// The options take up the rest of the cursor. It has to end with the message, see
+/// [`PacketCursor::truncate`] to drop the Ethernet padding of short packets.
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(PartialEq, Eq)]
+pub struct RouterSolicitation<B> {
+    options: B,
+}
+
+impl<B: PacketBuffer> RouterSolicitation<B> {
+    /// Takes the message body from `cursor`, which has to be the body of `header`.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_cursor(
+        header: &Icmpv6Header<B>,
+        cursor: &mut PacketCursor<B>,
+    ) -> Result<Self, NdpError> {
+        check_ndp_header(header, Icmpv6Type::RouterSolicitation)?;
+        let options = cursor.split_header_len(cursor.len())?;
+
+        Ok(RouterSolicitation { options })
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn options(&self) -> NdpOptionsIter<'_> {
+        NdpOptionsIter::new(&self.options)
+    }
+}
+
+/// Router advertisement message, see [`RouterSolicitation`] for the options.
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(PartialEq, Eq)]
+pub struct RouterAdvertisement<B> {
+    rest_of_header: [u8; 4],
+    // size of 8B
+    body: B,
+    options: B,
+}
+
+impl<B: PacketBuffer> RouterAdvertisement<B> {
+    /// Takes the message body from `cursor`, which has to be the body of `header`.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_cursor(
+        header: &Icmpv6Header<B>,
+        cursor: &mut PacketCursor<B>,
+    ) -> Result<Self, NdpError> {
+        // We read the first 8 bytes of the body:
+        // 4 bytes reachable time
+        // 4 bytes retrans timer
+        // ------------------
+        // 8 bytes total
+
+        let rest_of_header = check_ndp_header(header, Icmpv6Type::RouterAdvertisement)?;
+        let body = cursor.split_header::<8>()?;
+        let options = cursor.split_header_len(cursor.len())?;
+
+        Ok(RouterAdvertisement {
+            rest_of_header,
+            body,
+            options,
+        })
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_cur_hop_limit(&self) -> Result<u8, NdpError> {
+        self.rest_of_header
+            .get(RA_CUR_HOP_LIMIT)
+            .copied()
+            .ok_or(NdpError::OutOfBoundsBufferAccess)
+    }
+
+    /// Addresses are available via DHCPv6.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_managed_flag(&self) -> Result<bool, NdpError> {
+        Ok(self.get_flags()? & RA_FLAG_MANAGED != 0)
+    }
+
+    /// Other configuration is available via DHCPv6.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_other_flag(&self) -> Result<bool, NdpError> {
+        Ok(self.get_flags()? & RA_FLAG_OTHER != 0)
+    }
+
+    /// Lifetime of the default router in seconds, 0 if the router isn't a default router.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_router_lifetime(&self) -> Result<u16, NdpError> {
+        Ok(u16::from_be_bytes(
+            self.rest_of_header
+                .get(RA_ROUTER_LIFETIME_START..RA_ROUTER_LIFETIME_END)
+                .ok_or(NdpError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        ))
+    }
+
+    /// Reachable time in milliseconds.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_reachable_time(&self) -> Result<u32, NdpError> {
+        Ok(u32::from_be_bytes(
+            self.body
+                .get(RA_REACHABLE_TIME_START..RA_REACHABLE_TIME_END)
+                .ok_or(NdpError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        ))
+    }
+
+    /// Retransmission timer in milliseconds.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_retrans_timer(&self) -> Result<u32, NdpError> {
+        Ok(u32::from_be_bytes(
+            self.body
+                .get(RA_RETRANS_TIMER_START..RA_RETRANS_TIMER_END)
+                .ok_or(NdpError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        ))
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn options(&self) -> NdpOptionsIter<'_> {
+        NdpOptionsIter::new(&self.options)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn get_flags(&self) -> Result<u8, NdpError> {
+        self.rest_of_header
+            .get(RA_FLAGS)
+            .copied()
+            .ok_or(NdpError::OutOfBoundsBufferAccess)
+    }
+}
+
+/// Neighbor solicitation message, see [`RouterSolicitation`] for the options.
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(PartialEq, Eq)]
+pub struct NeighborSolicitation<B> {
+    // size of 16B
+    body: B,
+    options: B,
+}
+
+impl<B: PacketBuffer> NeighborSolicitation<B> {
+    /// Takes the message body from `cursor`, which has to be the body of `header`.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_cursor(
+        header: &Icmpv6Header<B>,
+        cursor: &mut PacketCursor<B>,
+    ) -> Result<Self, NdpError> {
+        // We read the first 16 bytes of the body:
+        // 16 bytes target address
+        // ------------------
+        // 16 bytes total
+
+        check_ndp_header(header, Icmpv6Type::NeighborSolicitation)?;
+        let body = cursor.split_header::<16>()?;
+        let options = cursor.split_header_len(cursor.len())?;
+
+        Ok(NeighborSolicitation { body, options })
+    }
+
+    /// The address which is resolved.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_target_address(&self) -> Result<&[u8; 16], NdpError> {
+        Ok(self
+            .body
+            .get(TARGET_ADDRESS_START..TARGET_ADDRESS_END)
+            .ok_or(NdpError::OutOfBoundsBufferAccess)?
+            .try_into()?)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn options(&self) -> NdpOptionsIter<'_> {
+        NdpOptionsIter::new(&self.options)
+    }
+}
+
+/// Neighbor advertisement message, see [`RouterSolicitation`] for the options.
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(PartialEq, Eq)]
+pub struct NeighborAdvertisement<B> {
+    rest_of_header: [u8; 4],
+    // size of 16B
+    body: B,
+    options: B,
+}
+
+impl<B: PacketBuffer> NeighborAdvertisement<B> {
+    /// Takes the message body from `cursor`, which has to be the body of `header`.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_cursor(
+        header: &Icmpv6Header<B>,
+        cursor: &mut PacketCursor<B>,
+    ) -> Result<Self, NdpError> {
+        // We read the first 16 bytes of the body:
+        // 16 bytes target address
+        // ------------------
+        // 16 bytes total
+
+        let rest_of_header = check_ndp_header(header, Icmpv6Type::NeighborAdvertisement)?;
+        let body = cursor.split_header::<16>()?;
+        let options = cursor.split_header_len(cursor.len())?;
+
+        Ok(NeighborAdvertisement {
+            rest_of_header,
+            body,
+            options,
+        })
+    }
+
+    /// The sender is a router.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_router_flag(&self) -> Result<bool, NdpError> {
+        Ok(self.get_flags()? & NA_FLAG_ROUTER != 0)
+    }
+
+    /// The advertisement answers a neighbor solicitation.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_solicited_flag(&self) -> Result<bool, NdpError> {
+        Ok(self.get_flags()? & NA_FLAG_SOLICITED != 0)
+    }
+
+    /// The advertisement should override cached link-layer addresses.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_override_flag(&self) -> Result<bool, NdpError> {
+        Ok(self.get_flags()? & NA_FLAG_OVERRIDE != 0)
+    }
+
+    /// The address whose link-layer address is advertised.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_target_address(&self) -> Result<&[u8; 16], NdpError> {
+        Ok(self
+            .body
+            .get(TARGET_ADDRESS_START..TARGET_ADDRESS_END)
+            .ok_or(NdpError::OutOfBoundsBufferAccess)?
+            .try_into()?)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn options(&self) -> NdpOptionsIter<'_> {
+        NdpOptionsIter::new(&self.options)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn get_flags(&self) -> Result<u8, NdpError> {
+        self.rest_of_header
+            .get(NA_FLAGS)
+            .copied()
+            .ok_or(NdpError::OutOfBoundsBufferAccess)
+    }
+}
+
+/// Checks type and code of a Neighbor Discovery message and returns the rest of the header.
+#[cfg_attr(feature = "inline", inline(always))]
+fn check_ndp_header<B: PacketBuffer>(
+    header: &Icmpv6Header<B>,
+    expected: Icmpv6Type,
+) -> Result<[u8; 4], NdpError> {
+    let icmp_type = header.get_type()?;
+    if Icmpv6Type::lookup_lenient(icmp_type) != expected {
+        return Err(NdpError::WrongMessageType { icmp_type });
+    }
+    let code = header.get_code()?;
+    if code != 0 {
+        return Err(NdpError::InvalidCode { code });
+    }
+    Ok(*header.get_rest_of_header()?)
+}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum NdpOption<'a> {
+    /// Usually the 6 byte MAC address of the sender.
+    SourceLinkLayerAddress(&'a [u8]),
+    /// Usually the 6 byte MAC address of the target.
+    TargetLinkLayerAddress(&'a [u8]),
+    PrefixInformation(PrefixInformation<'a>),
+    Mtu(u32),
+    /// Any other option, `data` excludes type and length.
+    Other {
+        option_type: u8,
+        data: &'a [u8],
+    },
+}
+
+/// Data of a prefix information option.
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct PrefixInformation<'a>(&'a [u8]);
+
+impl<'a> PrefixInformation<'a> {
+    /// Number of leading bits of the prefix which are valid.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_prefix_length(&self) -> Result<u8, NdpOptionError> {
+        self.0
+            .get(PREFIX_LENGTH)
+            .copied()
+            .ok_or(NdpOptionError::OutOfBoundsBufferAccess)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_on_link_flag(&self) -> Result<bool, NdpOptionError> {
+        Ok(self.get_flags()? & PREFIX_FLAG_ON_LINK != 0)
+    }
+
+    /// The prefix can be used for stateless address autoconfiguration.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_autonomous_flag(&self) -> Result<bool, NdpOptionError> {
+        Ok(self.get_flags()? & PREFIX_FLAG_AUTONOMOUS != 0)
+    }
+
+    /// Valid lifetime in seconds.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_valid_lifetime(&self) -> Result<u32, NdpOptionError> {
+        Ok(u32::from_be_bytes(
+            self.0
+                .get(PREFIX_VALID_LIFETIME_START..PREFIX_VALID_LIFETIME_END)
+                .ok_or(NdpOptionError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        ))
+    }
+
+    /// Preferred lifetime in seconds.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_preferred_lifetime(&self) -> Result<u32, NdpOptionError> {
+        Ok(u32::from_be_bytes(
+            self.0
+                .get(PREFIX_PREFERRED_LIFETIME_START..PREFIX_PREFERRED_LIFETIME_END)
+                .ok_or(NdpOptionError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        ))
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_prefix(&self) -> Result<&'a [u8; 16], NdpOptionError> {
+        Ok(self
+            .0
+            .get(PREFIX_START..PREFIX_END)
+            .ok_or(NdpOptionError::OutOfBoundsBufferAccess)?
+            .try_into()?)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn get_flags(&self) -> Result<u8, NdpOptionError> {
+        self.0
+            .get(PREFIX_FLAGS)
+            .copied()
+            .ok_or(NdpOptionError::OutOfBoundsBufferAccess)
+    }
+}
+
+/// Iterator over the options of a Neighbor Discovery message.
+pub type NdpOptionsIter<'a> = TlvIter<'a, NdpOptionParser>;
+
+/// Parses the options of a [`NdpOptionsIter`].
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Default)]
+pub struct NdpOptionParser;
+
+impl<'a> TlvParser<'a> for NdpOptionParser {
+    type Item = NdpOption<'a>;
+    type Error = NdpOptionError;
+
+    const MAX: usize = MAX_NDP_OPTIONS;
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn parse(
+        &mut self,
+        options: &'a [u8],
+        offset: &mut usize,
+    ) -> Option<Result<NdpOption<'a>, NdpOptionError>> {
+        let option_type = *options.get(*offset)?;
+        Some(parse_option(options, option_type, offset))
+    }
+}
+
+#[cfg_attr(feature = "inline", inline(always))]
+fn parse_option<'a>(
+    options: &'a [u8],
+    option_type: u8,
+    offset: &mut usize,
+) -> Result<NdpOption<'a>, NdpOptionError> {
+    let length = *options
+        .get(*offset + 1)
+        .ok_or(NdpOptionError::Truncated { option_type })?;
+    if length == 0 {
+        return Err(NdpOptionError::InvalidLength {
+            option_type,
+            length,
+        });
+    }
+    let option_len = length as usize * NDP_OPTION_LENGTH_UNIT;
+    let data = options
+        .get(*offset + 2..*offset + option_len)
+        .ok_or(NdpOptionError::Truncated { option_type })?;
+
+    let option = match NdpOptionType::lookup_lenient(option_type) {
+        NdpOptionType::SourceLinkLayerAddress => NdpOption::SourceLinkLayerAddress(data),
+        NdpOptionType::TargetLinkLayerAddress => NdpOption::TargetLinkLayerAddress(data),
+        NdpOptionType::PrefixInformation => {
+            if length != 4 {
+                return Err(NdpOptionError::InvalidLength {
+                    option_type,
+                    length,
+                });
+            }
+            NdpOption::PrefixInformation(PrefixInformation(data))
+        }
+        NdpOptionType::Mtu => {
+            if length != 1 {
+                return Err(NdpOptionError::InvalidLength {
+                    option_type,
+                    length,
+                });
+            }
+            // 2 bytes reserved, 4 bytes MTU
+            NdpOption::Mtu(u32::from_be_bytes(
+                data.get(2..6)
+                    .ok_or(NdpOptionError::Truncated { option_type })?
+                    .try_into()?,
+            ))
+        }
+        _ => NdpOption::Other { option_type, data },
+    };
+
+    *offset += option_len;
+    Ok(option)
+}
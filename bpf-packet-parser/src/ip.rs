@@ -0,0 +1,3 @@
+mod header_values;
+
+pub use header_values::*;
@@ -0,0 +1,249 @@
+crate::macros::generate_matching_enum_impl! {
+    /// Protocol field of IPv4 and next header field of IPv6.
+    ///
+    /// Sources:
+    /// `<https://www.iana.org/assignments/protocol-numbers/protocol-numbers.xhtml>`
+    #[repr(u8)]
+    #[cfg_attr(feature = "fmt", derive(Debug))]
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub enum IpProtocol {
+        /// IPv6 Hop-by-Hop Option
+        HopOpt = 0,
+        /// Internet Control Message Protocol
+        Icmp = 1,
+        /// Internet Group Management Protocol
+        Igmp = 2,
+        /// Gateway-to-Gateway Protocol
+        Ggp = 3,
+        /// IPv4 encapsulation
+        IpV4 = 4,
+        /// Internet Stream Protocol
+        St = 5,
+        /// Transmission Control Protocol
+        Tcp = 6,
+        /// Core-based trees
+        Cbt = 7,
+        /// Exterior Gateway Protocol
+        Egp = 8,
+        /// Interior Gateway Protocol, any private interior gateway
+        Igp = 9,
+        BbnRccMon = 10,
+        /// Network Voice Protocol
+        NvpII = 11,
+        Pup = 12,
+        Argus = 13,
+        Emcon = 14,
+        /// Cross Net Debugger
+        Xnet = 15,
+        Chaos = 16,
+        /// User Datagram Protocol
+        Udp = 17,
+        /// Multiplexing
+        Mux = 18,
+        DcnMeas = 19,
+        /// Host Monitoring Protocol
+        Hmp = 20,
+        /// Packet Radio Measurement
+        Prm = 21,
+        XnsIdp = 22,
+        Trunk1 = 23,
+        Trunk2 = 24,
+        Leaf1 = 25,
+        Leaf2 = 26,
+        /// Reliable Data Protocol
+        Rdp = 27,
+        /// Internet Reliable Transaction Protocol
+        Irtp = 28,
+        IsoTp4 = 29,
+        /// Bulk Data Transfer Protocol
+        Netblt = 30,
+        MfeNsp = 31,
+        MeritInp = 32,
+        /// Datagram Congestion Control Protocol
+        Dccp = 33,
+        /// Third Party Connect Protocol
+        ThirdPartyConnect = 34,
+        /// Inter-Domain Policy Routing Protocol
+        Idpr = 35,
+        /// Xpress Transport Protocol
+        Xtp = 36,
+        /// Datagram Delivery Protocol
+        Ddp = 37,
+        IdprCmtp = 38,
+        /// TP++ Transport Protocol
+        TpPlusPlus = 39,
+        /// IL Transport Protocol
+        Il = 40,
+        /// IPv6 encapsulation
+        IpV6 = 41,
+        /// Source Demand Routing Protocol
+        Sdrp = 42,
+        /// Routing Header for IPv6
+        IpV6Route = 43,
+        /// Fragment Header for IPv6
+        IpV6Frag = 44,
+        /// Inter-Domain Routing Protocol
+        Idrp = 45,
+        /// Reservation Protocol
+        Rsvp = 46,
+        /// Generic Routing Encapsulation
+        Gre = 47,
+        /// Dynamic Source Routing Protocol
+        Dsr = 48,
+        Bna = 49,
+        /// Encapsulating Security Payload
+        Esp = 50,
+        /// Authentication Header
+        Ah = 51,
+        /// Integrated Net Layer Security Protocol
+        INlsp = 52,
+        Swipe = 53,
+        /// NBMA Address Resolution Protocol
+        Narp = 54,
+        /// Minimal IPv4 Encapsulation
+        MinIpV4 = 55,
+        /// Transport Layer Security Protocol using Kryptonet key management
+        Tlsp = 56,
+        Skip = 57,
+        /// ICMP for IPv6
+        IcmpV6 = 58,
+        /// No Next Header for IPv6
+        IpV6NoNxt = 59,
+        /// Destination Options for IPv6
+        IpV6Opts = 60,
+        AnyHostInternal = 61,
+        Cftp = 62,
+        AnyLocalNetwork = 63,
+        SatExpak = 64,
+        Kryptolan = 65,
+        /// MIT Remote Virtual Disk Protocol
+        Rvd = 66,
+        /// Internet Pluribus Packet Core
+        Ippc = 67,
+        AnyDistributedFileSystem = 68,
+        SatMon = 69,
+        Visa = 70,
+        /// Internet Packet Core Utility
+        Ipcv = 71,
+        /// Computer Protocol Network Executive
+        Cpnx = 72,
+        /// Computer Protocol Heart Beat
+        Cphb = 73,
+        /// Wang Span Network
+        Wsn = 74,
+        /// Packet Video Protocol
+        Pvp = 75,
+        BrSatMon = 76,
+        SunNd = 77,
+        WbMon = 78,
+        WbExpak = 79,
+        IsoIp = 80,
+        Vmtp = 81,
+        SecureVmtp = 82,
+        Vines = 83,
+        /// Internet Protocol Traffic Manager, also used by TTP
+        Iptm = 84,
+        NsfnetIgp = 85,
+        /// Dissimilar Gateway Protocol
+        Dgp = 86,
+        Tcf = 87,
+        /// Enhanced Interior Gateway Routing Protocol
+        Eigrp = 88,
+        /// Open Shortest Path First
+        Ospf = 89,
+        SpriteRpc = 90,
+        /// Locus Address Resolution Protocol
+        Larp = 91,
+        /// Multicast Transport Protocol
+        Mtp = 92,
+        Ax25 = 93,
+        /// IP-within-IP Encapsulation Protocol
+        IpIp = 94,
+        Micp = 95,
+        SccSp = 96,
+        /// Ethernet-within-IP Encapsulation
+        EtherIp = 97,
+        /// Encapsulation Header
+        Encap = 98,
+        AnyPrivateEncryption = 99,
+        Gmtp = 100,
+        /// Ipsilon Flow Management Protocol
+        Ifmp = 101,
+        /// PNNI over IP
+        Pnni = 102,
+        /// Protocol Independent Multicast
+        Pim = 103,
+        Aris = 104,
+        Scps = 105,
+        Qnx = 106,
+        /// Active Networks
+        ActiveNetworks = 107,
+        /// IP Payload Compression Protocol
+        IpComp = 108,
+        /// Sitara Networks Protocol
+        Snp = 109,
+        CompaqPeer = 110,
+        IpxInIp = 111,
+        /// Virtual Router Redundancy Protocol
+        Vrrp = 112,
+        /// PGM Reliable Transport Protocol
+        Pgm = 113,
+        AnyZeroHop = 114,
+        /// Layer Two Tunneling Protocol Version 3
+        L2tp = 115,
+        /// D-II Data Exchange
+        Ddx = 116,
+        /// Interactive Agent Transfer Protocol
+        Iatp = 117,
+        /// Schedule Transfer Protocol
+        Stp = 118,
+        /// SpectraLink Radio Protocol
+        Srp = 119,
+        Uti = 120,
+        /// Simple Message Protocol
+        Smp = 121,
+        Sm = 122,
+        /// Performance Transparency Protocol
+        Ptp = 123,
+        IsisOverIpV4 = 124,
+        Fire = 125,
+        /// Combat Radio Transport Protocol
+        Crtp = 126,
+        /// Combat Radio User Datagram
+        Crudp = 127,
+        Sscopmce = 128,
+        Iplt = 129,
+        /// Secure Packet Shield
+        Sps = 130,
+        /// Private IP Encapsulation within IP
+        Pipe = 131,
+        /// Stream Control Transmission Protocol
+        Sctp = 132,
+        /// Fibre Channel
+        Fc = 133,
+        RsvpE2eIgnore = 134,
+        /// Mobility Header for IPv6
+        MobilityHeader = 135,
+        UdpLite = 136,
+        MplsInIp = 137,
+        /// MANET Protocols
+        Manet = 138,
+        /// Host Identity Protocol
+        Hip = 139,
+        /// Shim6 Protocol
+        Shim6 = 140,
+        /// Wrapped Encapsulating Security Payload
+        Wesp = 141,
+        /// Robust Header Compression
+        Rohc = 142,
+        Ethernet = 143,
+        /// Aggregation and Fragmentation for IP-TFS
+        AggFrag = 144,
+        /// Network Service Header
+        Nsh = 145,
+        /// Reserved for experimentation and testing, RFC 3692.
+        Experimental = 253..=254,
+    }
+    (error_value: 0xFF),
+    (lookup_table: true)
+}
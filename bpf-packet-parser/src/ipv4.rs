@@ -0,0 +1,260 @@
+mod error;
+
+pub use error::*;
+
+use crate::buffer::PacketBuffer;
+use crate::cursor::PacketCursor;
+use crate::ip::IpProtocol;
+#[cfg(feature = "fmt")]
+use core::fmt::Debug;
+
+// All ranges are [start..end).
+// This means start is included, end is not included.
+pub static VERSION_IHL: usize = 0;
+pub static DSCP_ECN: usize = 1;
+pub static TOTAL_LENGTH_START: usize = 2;
+pub static TOTAL_LENGTH_END: usize = 4;
+pub static IDENTIFICATION_START: usize = 4;
+pub static IDENTIFICATION_END: usize = 6;
+pub static FLAGS_FRAGMENT_OFFSET_START: usize = 6;
+pub static FLAGS_FRAGMENT_OFFSET_END: usize = 8;
+pub static TTL: usize = 8;
+pub static PROTOCOL: usize = 9;
+pub static CHECKSUM_START: usize = 10;
+pub static CHECKSUM_END: usize = 12;
+pub static SOURCE_ADDRESS_START: usize = 12;
+pub static SOURCE_ADDRESS_END: usize = 16;
+pub static DESTINATION_ADDRESS_START: usize = 16;
+pub static DESTINATION_ADDRESS_END: usize = 20;
+pub static OPTIONS_START: usize = 20;
+
+/// Size of a header without options, IHL 5.
+pub static IPV4_MIN_HEADER_SIZE: usize = 20;
+/// Size of a header with the maximum IHL of 15.
+pub static IPV4_MAX_HEADER_SIZE: usize = 60;
+
+pub static FLAG_DONT_FRAGMENT: u16 = 0x4000;
+pub static FLAG_MORE_FRAGMENTS: u16 = 0x2000;
+pub static FRAGMENT_OFFSET_MASK: u16 = 0x1FFF;
+
+/// IPv4 header including options, found in the payload of frames with
+/// [`EtherType::IpV4`](crate::ethernet::EtherType::IpV4).
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(PartialEq, Eq)]
+pub struct Ipv4Header<B> {
+    // size of 20B - 60B
+    header: B,
+}
+
+impl<B: PacketBuffer> Ipv4Header<B> {
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn new_bpf(buf: B, end: usize) -> Result<(Self, B), Ipv4BpfCreationError> {
+        let mut cursor = PacketCursor::new(buf, end);
+        let header = Self::from_cursor(&mut cursor)?;
+        Ok((header, cursor.into_inner()))
+    }
+
+    /// Takes the header including options from the front of `cursor` and leaves the cursor at
+    /// the L4 payload.
+    ///
+    /// The payload is not cut to the total length, it may still contain the Ethernet padding
+    /// of short packets.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_cursor(cursor: &mut PacketCursor<B>) -> Result<Self, Ipv4BpfCreationError> {
+        // We read at least the first 20 bytes of the packet:
+        // 1 byte version and IHL
+        // 1 byte DSCP and ECN
+        // 2 bytes total length
+        // 2 bytes identification
+        // 2 bytes flags and fragment offset
+        // 1 byte TTL
+        // 1 byte protocol
+        // 2 bytes checksum
+        // 4 bytes source address
+        // 4 bytes destination address
+        // ------------------
+        // 20 bytes total
+        // The options make up the remaining IHL * 4 - 20 bytes, at most 40 bytes.
+
+        let fixed = cursor.peek::<20>()?;
+        let version_ihl = *fixed
+            .get(VERSION_IHL)
+            .ok_or(Ipv4BpfCreationError::OutOfBoundsBufferAccess)?;
+        let total_length = u16::from_be_bytes(
+            fixed
+                .get(TOTAL_LENGTH_START..TOTAL_LENGTH_END)
+                .ok_or(Ipv4BpfCreationError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        );
+
+        let version = version_ihl >> 4;
+        if version != 4 {
+            return Err(Ipv4BpfCreationError::InvalidVersion { version });
+        }
+
+        let ihl = version_ihl & 0x0F;
+        let header_len = ihl as usize * 4;
+        if header_len < IPV4_MIN_HEADER_SIZE {
+            return Err(Ipv4BpfCreationError::InvalidIhl { ihl });
+        }
+
+        if (total_length as usize) < header_len {
+            return Err(Ipv4BpfCreationError::InvalidTotalLength {
+                total_length,
+                header_len,
+            });
+        }
+
+        let header = cursor.split_header_len(header_len)?;
+
+        Ok(Ipv4Header { header })
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_version(&self) -> Result<u8, Ipv4GetHeaderError> {
+        Ok(self.get_version_ihl()? >> 4)
+    }
+
+    /// Internet header length in 32 bit words, see [`Ipv4Header::get_header_len`] for bytes.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_ihl(&self) -> Result<u8, Ipv4GetHeaderError> {
+        Ok(self.get_version_ihl()? & 0x0F)
+    }
+
+    /// Length of the header including options in bytes.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_header_len(&self) -> usize {
+        self.header.len()
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_dscp(&self) -> Result<u8, Ipv4GetHeaderError> {
+        Ok(self.get_dscp_ecn()? >> 2)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_ecn(&self) -> Result<u8, Ipv4GetHeaderError> {
+        Ok(self.get_dscp_ecn()? & 0x03)
+    }
+
+    /// Length of header and payload in bytes.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_total_length(&self) -> Result<u16, Ipv4GetHeaderError> {
+        self.get_u16(TOTAL_LENGTH_START, TOTAL_LENGTH_END)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_identification(&self) -> Result<u16, Ipv4GetHeaderError> {
+        self.get_u16(IDENTIFICATION_START, IDENTIFICATION_END)
+    }
+
+    /// The 3 flag bits: reserved, don't fragment, more fragments.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_flags(&self) -> Result<u8, Ipv4GetHeaderError> {
+        Ok((self.get_flags_fragment_offset()? >> 13) as u8)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_dont_fragment(&self) -> Result<bool, Ipv4GetHeaderError> {
+        Ok(self.get_flags_fragment_offset()? & FLAG_DONT_FRAGMENT != 0)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_more_fragments(&self) -> Result<bool, Ipv4GetHeaderError> {
+        Ok(self.get_flags_fragment_offset()? & FLAG_MORE_FRAGMENTS != 0)
+    }
+
+    /// Fragment offset in units of 8 bytes.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_fragment_offset(&self) -> Result<u16, Ipv4GetHeaderError> {
+        Ok(self.get_flags_fragment_offset()? & FRAGMENT_OFFSET_MASK)
+    }
+
+    /// True for every fragment of a fragmented packet, including the first one.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn is_fragment(&self) -> Result<bool, Ipv4GetHeaderError> {
+        let value = self.get_flags_fragment_offset()?;
+        Ok(value & (FLAG_MORE_FRAGMENTS | FRAGMENT_OFFSET_MASK) != 0)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_ttl(&self) -> Result<u8, Ipv4GetHeaderError> {
+        self.get_u8(TTL)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_protocol(&self) -> Result<u8, Ipv4GetHeaderError> {
+        self.get_u8(PROTOCOL)
+    }
+
+    /// The protocol as [`IpProtocol`], unassigned numbers are returned as
+    /// [`IpProtocol::IpProtocolErrorVariant`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_typed_protocol(&self) -> Result<IpProtocol, Ipv4GetHeaderError> {
+        Ok(IpProtocol::lookup_lenient(self.get_protocol()?))
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_checksum(&self) -> Result<u16, Ipv4GetHeaderError> {
+        self.get_u16(CHECKSUM_START, CHECKSUM_END)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_source(&self) -> Result<&[u8; 4], Ipv4GetHeaderError> {
+        Ok(self
+            .header
+            .get(SOURCE_ADDRESS_START..SOURCE_ADDRESS_END)
+            .ok_or(Ipv4GetHeaderError::OutOfBoundsBufferAccess)?
+            .try_into()?)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_destination(&self) -> Result<&[u8; 4], Ipv4GetHeaderError> {
+        Ok(self
+            .header
+            .get(DESTINATION_ADDRESS_START..DESTINATION_ADDRESS_END)
+            .ok_or(Ipv4GetHeaderError::OutOfBoundsBufferAccess)?
+            .try_into()?)
+    }
+
+    /// The options, empty for IHL 5.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_options(&self) -> Result<&[u8], Ipv4GetHeaderError> {
+        self.header
+            .get(OPTIONS_START..)
+            .ok_or(Ipv4GetHeaderError::OutOfBoundsBufferAccess)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn get_version_ihl(&self) -> Result<u8, Ipv4GetHeaderError> {
+        self.get_u8(VERSION_IHL)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn get_dscp_ecn(&self) -> Result<u8, Ipv4GetHeaderError> {
+        self.get_u8(DSCP_ECN)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn get_flags_fragment_offset(&self) -> Result<u16, Ipv4GetHeaderError> {
+        self.get_u16(FLAGS_FRAGMENT_OFFSET_START, FLAGS_FRAGMENT_OFFSET_END)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn get_u8(&self, index: usize) -> Result<u8, Ipv4GetHeaderError> {
+        self.header
+            .get(index)
+            .copied()
+            .ok_or(Ipv4GetHeaderError::OutOfBoundsBufferAccess)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn get_u16(&self, start: usize, end: usize) -> Result<u16, Ipv4GetHeaderError> {
+        Ok(u16::from_be_bytes(
+            self.header
+                .get(start..end)
+                .ok_or(Ipv4GetHeaderError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        ))
+    }
+}
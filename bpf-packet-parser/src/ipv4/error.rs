@@ -0,0 +1,150 @@
+use crate::cursor::CursorError;
+use core::array::TryFromSliceError;
+#[cfg(feature = "fmt")]
+use core::fmt::{Debug, Display, Formatter};
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum Ipv4Error {
+    BpfCreation(Ipv4BpfCreationError),
+    GetHeader(Ipv4GetHeaderError),
+}
+
+#[cfg(feature = "fmt")]
+impl Display for Ipv4Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Ipv4Error::BpfCreation(err) => {
+                write!(f, "{err}")
+            }
+            Ipv4Error::GetHeader(err) => {
+                write!(f, "{err}")
+            }
+        }
+    }
+}
+
+impl From<Ipv4BpfCreationError> for Ipv4Error {
+    fn from(value: Ipv4BpfCreationError) -> Self {
+        Self::BpfCreation(value)
+    }
+}
+
+impl From<Ipv4GetHeaderError> for Ipv4Error {
+    fn from(value: Ipv4GetHeaderError) -> Self {
+        Self::GetHeader(value)
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for Ipv4Error {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum Ipv4BpfCreationError {
+    InvalidVersion {
+        version: u8,
+    },
+    InvalidIhl {
+        ihl: u8,
+    },
+    InvalidTotalLength {
+        total_length: u16,
+        header_len: usize,
+    },
+    PacketTooShort {
+        size: usize,
+        expected: usize,
+    },
+    BpfOutOfBounds,
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for Ipv4BpfCreationError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+impl From<CursorError> for Ipv4BpfCreationError {
+    fn from(value: CursorError) -> Self {
+        match value {
+            CursorError::TooShort { size, expected } => Self::PacketTooShort { size, expected },
+            CursorError::BpfOutOfBounds => Self::BpfOutOfBounds,
+            CursorError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            CursorError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for Ipv4BpfCreationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidVersion { version } => {
+                write!(f, "IPv4 header expected version 4, was: {version}")
+            }
+            Self::InvalidIhl { ihl } => {
+                write!(f, "IPv4 header expected IHL of at least 5, was: {ihl}")
+            }
+            Self::InvalidTotalLength {
+                total_length,
+                header_len,
+            } => {
+                write!(
+                    f,
+                    "IPv4 total length smaller than the {header_len} byte header, was: {total_length}"
+                )
+            }
+            Self::PacketTooShort { size, expected } => {
+                write!(
+                    f,
+                    "IPv4 packet expected to be at least {expected} bytes, was: {size}"
+                )
+            }
+            Self::BpfOutOfBounds => {
+                write!(f, "Bpf out of bounds")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for Ipv4BpfCreationError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum Ipv4GetHeaderError {
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for Ipv4GetHeaderError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for Ipv4GetHeaderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for Ipv4GetHeaderError {}
@@ -0,0 +1,299 @@
+mod error;
+
+pub use error::*;
+
+use crate::buffer::PacketBuffer;
+use crate::cursor::PacketCursor;
+use crate::ip::IpProtocol;
+#[cfg(feature = "fmt")]
+use core::fmt::Debug;
+
+// All ranges are [start..end).
+// This means start is included, end is not included.
+pub static VERSION_TRAFFIC_CLASS_FLOW_LABEL_START: usize = 0;
+pub static VERSION_TRAFFIC_CLASS_FLOW_LABEL_END: usize = 4;
+pub static PAYLOAD_LENGTH_START: usize = 4;
+pub static PAYLOAD_LENGTH_END: usize = 6;
+pub static NEXT_HEADER: usize = 6;
+pub static HOP_LIMIT: usize = 7;
+pub static SOURCE_ADDRESS_START: usize = 8;
+pub static SOURCE_ADDRESS_END: usize = 24;
+pub static DESTINATION_ADDRESS_START: usize = 24;
+pub static DESTINATION_ADDRESS_END: usize = 40;
+
+pub static IPV6_HEADER_SIZE: usize = 40;
+
+// Next header values of the extension headers the walker follows.
+pub static EXT_HOP_BY_HOP_OPTIONS: u8 = IpProtocol::HopOpt as u8;
+pub static EXT_ROUTING: u8 = IpProtocol::IpV6Route as u8;
+pub static EXT_FRAGMENT: u8 = IpProtocol::IpV6Frag as u8;
+pub static EXT_AUTHENTICATION: u8 = IpProtocol::Ah as u8;
+pub static EXT_DESTINATION_OPTIONS: u8 = IpProtocol::IpV6Opts as u8;
+pub static FRAGMENT_HEADER_SIZE: usize = 8;
+
+/// Maximum number of extension headers the walker follows, see
+/// [bounded loops](crate#bounded-loops).
+pub const MAX_EXTENSION_HEADERS: usize = 6;
+
+/// True for the next header values of extension headers walked by
+/// [`Ipv6Header::walk_extension_headers`].
+#[cfg_attr(feature = "inline", inline(always))]
+pub fn is_extension_header(next_header: u8) -> bool {
+    next_header == EXT_HOP_BY_HOP_OPTIONS
+        || next_header == EXT_ROUTING
+        || next_header == EXT_FRAGMENT
+        || next_header == EXT_AUTHENTICATION
+        || next_header == EXT_DESTINATION_OPTIONS
+}
+
+/// Fixed IPv6 header, found in the payload of frames with
+/// [`EtherType::IpV6`](crate::ethernet::EtherType::IpV6).
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(PartialEq, Eq)]
+pub struct Ipv6Header<B> {
+    // size of 40B
+    header: B,
+}
+
+impl<B: PacketBuffer> Ipv6Header<B> {
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn new_bpf(buf: B, end: usize) -> Result<(Self, B), Ipv6BpfCreationError> {
+        let mut cursor = PacketCursor::new(buf, end);
+        let header = Self::from_cursor(&mut cursor)?;
+        Ok((header, cursor.into_inner()))
+    }
+
+    /// Takes the fixed header from the front of `cursor` and leaves the cursor at the first
+    /// extension header or the upper-layer header.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_cursor(cursor: &mut PacketCursor<B>) -> Result<Self, Ipv6BpfCreationError> {
+        // We read the first 40 bytes of the packet:
+        // 4 bytes version, traffic class and flow label
+        // 2 bytes payload length
+        // 1 byte next header
+        // 1 byte hop limit
+        // 16 bytes source address
+        // 16 bytes destination address
+        // ------------------
+        // 40 bytes total
+
+        let version = *cursor
+            .peek::<1>()?
+            .first()
+            .ok_or(Ipv6BpfCreationError::OutOfBoundsBufferAccess)?
+            >> 4;
+        if version != 6 {
+            return Err(Ipv6BpfCreationError::InvalidVersion { version });
+        }
+
+        let header = cursor.split_header::<40>()?;
+
+        Ok(Ipv6Header { header })
+    }
+
+    /// Walks the extension header chain at the front of `cursor`, which has to be the payload
+    /// of this header. The walk stops at the first next header value which isn't accepted by
+    /// [`is_extension_header`] or after [`MAX_EXTENSION_HEADERS`] headers.
+    ///
+    /// Returns the upper-layer protocol and its offset in `cursor`. The cursor is not moved.
+    /// Non-first fragments carry no upper-layer header, they fail with
+    /// [`Ipv6ExtensionHeaderError::NonFirstFragment`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn walk_extension_headers(
+        &self,
+        cursor: &PacketCursor<B>,
+    ) -> Result<(u8, usize), Ipv6ExtensionHeaderError> {
+        let mut next_header = self.get_next_header()?;
+        let mut offset = 0;
+
+        for _ in 0..MAX_EXTENSION_HEADERS {
+            if !is_extension_header(next_header) {
+                break;
+            }
+
+            // Every extension header starts with:
+            // 1 byte next header
+            // 1 byte header extension length
+            let [following, ext_len] = *cursor.peek_at::<2>(offset)?;
+            let header_len = if next_header == EXT_FRAGMENT {
+                // 1 byte next header
+                // 1 byte reserved
+                // 13 bits fragment offset, 2 bits reserved, 1 bit more fragments flag
+                let [_, _, offset_high, offset_low] = *cursor.peek_at::<4>(offset)?;
+                let fragment_offset = u16::from_be_bytes([offset_high, offset_low]) >> 3;
+                if fragment_offset != 0 {
+                    // The headers behind a non-first fragment header are fragment data.
+                    return Err(Ipv6ExtensionHeaderError::NonFirstFragment {
+                        next_header: following,
+                    });
+                }
+                FRAGMENT_HEADER_SIZE
+            } else if next_header == EXT_AUTHENTICATION {
+                // Length in 4 byte units, not counting the first 8 bytes.
+                (ext_len as usize + 2) * 4
+            } else {
+                // Length in 8 byte units, not counting the first 8 bytes.
+                (ext_len as usize + 1) * 8
+            };
+            cursor.check_len(offset + header_len)?;
+
+            offset += header_len;
+            next_header = following;
+        }
+
+        if is_extension_header(next_header) {
+            return Err(Ipv6ExtensionHeaderError::ChainTooDeep);
+        }
+
+        Ok((next_header, offset))
+    }
+
+    /// Like [`Ipv6Header::walk_extension_headers`], but splits the extension headers off the
+    /// front of `cursor` and leaves the cursor at the upper-layer header.
+    ///
+    /// Returns the upper-layer protocol and the extension headers.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn split_extension_headers(
+        &self,
+        cursor: &mut PacketCursor<B>,
+    ) -> Result<(u8, B), Ipv6ExtensionHeaderError> {
+        let (next_header, offset) = self.walk_extension_headers(cursor)?;
+        let extension_headers = cursor.split_header_len(offset)?;
+        Ok((next_header, extension_headers))
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_version(&self) -> Result<u8, Ipv6GetHeaderError> {
+        Ok((self.get_version_traffic_class_flow_label()? >> 28) as u8)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_traffic_class(&self) -> Result<u8, Ipv6GetHeaderError> {
+        Ok((self.get_version_traffic_class_flow_label()? >> 20) as u8)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_dscp(&self) -> Result<u8, Ipv6GetHeaderError> {
+        Ok(self.get_traffic_class()? >> 2)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_ecn(&self) -> Result<u8, Ipv6GetHeaderError> {
+        Ok(self.get_traffic_class()? & 0x03)
+    }
+
+    /// The 20 bit flow label.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_flow_label(&self) -> Result<u32, Ipv6GetHeaderError> {
+        Ok(self.get_version_traffic_class_flow_label()? & 0x000F_FFFF)
+    }
+
+    /// Length of the payload including extension headers in bytes.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_payload_length(&self) -> Result<u16, Ipv6GetHeaderError> {
+        Ok(u16::from_be_bytes(
+            self.header
+                .get(PAYLOAD_LENGTH_START..PAYLOAD_LENGTH_END)
+                .ok_or(Ipv6GetHeaderError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        ))
+    }
+
+    /// The protocol of the first extension header or of the upper-layer header.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_next_header(&self) -> Result<u8, Ipv6GetHeaderError> {
+        self.header
+            .get(NEXT_HEADER)
+            .copied()
+            .ok_or(Ipv6GetHeaderError::OutOfBoundsBufferAccess)
+    }
+
+    /// The next header as [`IpProtocol`], unassigned numbers are returned as
+    /// [`IpProtocol::IpProtocolErrorVariant`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_typed_next_header(&self) -> Result<IpProtocol, Ipv6GetHeaderError> {
+        Ok(IpProtocol::lookup_lenient(self.get_next_header()?))
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_hop_limit(&self) -> Result<u8, Ipv6GetHeaderError> {
+        self.header
+            .get(HOP_LIMIT)
+            .copied()
+            .ok_or(Ipv6GetHeaderError::OutOfBoundsBufferAccess)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_source(&self) -> Result<&[u8; 16], Ipv6GetHeaderError> {
+        Ok(self
+            .header
+            .get(SOURCE_ADDRESS_START..SOURCE_ADDRESS_END)
+            .ok_or(Ipv6GetHeaderError::OutOfBoundsBufferAccess)?
+            .try_into()?)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_destination(&self) -> Result<&[u8; 16], Ipv6GetHeaderError> {
+        Ok(self
+            .header
+            .get(DESTINATION_ADDRESS_START..DESTINATION_ADDRESS_END)
+            .ok_or(Ipv6GetHeaderError::OutOfBoundsBufferAccess)?
+            .try_into()?)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn get_version_traffic_class_flow_label(&self) -> Result<u32, Ipv6GetHeaderError> {
+        Ok(u32::from_be_bytes(
+            self.header
+                .get(VERSION_TRAFFIC_CLASS_FLOW_LABEL_START..VERSION_TRAFFIC_CLASS_FLOW_LABEL_END)
+                .ok_or(Ipv6GetHeaderError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        ))
+    }
+}
+
+#[cfg(all(test, feature = "fmt"))]
+mod tests {
+    use super::*;
+
+    fn packet(fragment_offset: u16) -> [u8; 64] {
+        let mut packet = [0u8; 64];
+        packet[0] = 0x60;
+        packet[NEXT_HEADER] = EXT_FRAGMENT;
+        // Fragment header, announces a destination options header.
+        packet[40] = EXT_DESTINATION_OPTIONS;
+        packet[42..44].copy_from_slice(&(fragment_offset << 3).to_be_bytes());
+        // Destination options header of 8 bytes followed by UDP in the first fragment,
+        // fragment data in any other.
+        packet[48] = IpProtocol::Udp as u8;
+        packet
+    }
+
+    #[test]
+    fn walk_extension_headers_rejects_non_first_fragment() {
+        let packet = packet(1);
+        let buf: &[u8] = &packet;
+        let mut cursor = PacketCursor::new(buf, buf.as_ptr() as usize + buf.len());
+        let header = Ipv6Header::from_cursor(&mut cursor).unwrap();
+
+        assert_eq!(
+            header.walk_extension_headers(&cursor),
+            Err(Ipv6ExtensionHeaderError::NonFirstFragment {
+                next_header: EXT_DESTINATION_OPTIONS
+            })
+        );
+    }
+
+    #[test]
+    fn walk_extension_headers_continues_behind_first_fragment() {
+        let packet = packet(0);
+        let buf: &[u8] = &packet;
+        let mut cursor = PacketCursor::new(buf, buf.as_ptr() as usize + buf.len());
+        let header = Ipv6Header::from_cursor(&mut cursor).unwrap();
+
+        assert_eq!(
+            header.walk_extension_headers(&cursor),
+            Ok((IpProtocol::Udp as u8, FRAGMENT_HEADER_SIZE + 8))
+        );
+    }
+}
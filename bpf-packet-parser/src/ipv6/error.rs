@@ -0,0 +1,220 @@
+use crate::cursor::CursorError;
+#[cfg(feature = "fmt")]
+use crate::ipv6::MAX_EXTENSION_HEADERS;
+use core::array::TryFromSliceError;
+#[cfg(feature = "fmt")]
+use core::fmt::{Debug, Display, Formatter};
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum Ipv6Error {
+    BpfCreation(Ipv6BpfCreationError),
+    ExtensionHeader(Ipv6ExtensionHeaderError),
+    GetHeader(Ipv6GetHeaderError),
+}
+
+#[cfg(feature = "fmt")]
+impl Display for Ipv6Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Ipv6Error::BpfCreation(err) => {
+                write!(f, "{err}")
+            }
+            Ipv6Error::ExtensionHeader(err) => {
+                write!(f, "{err}")
+            }
+            Ipv6Error::GetHeader(err) => {
+                write!(f, "{err}")
+            }
+        }
+    }
+}
+
+impl From<Ipv6BpfCreationError> for Ipv6Error {
+    fn from(value: Ipv6BpfCreationError) -> Self {
+        Self::BpfCreation(value)
+    }
+}
+
+impl From<Ipv6ExtensionHeaderError> for Ipv6Error {
+    fn from(value: Ipv6ExtensionHeaderError) -> Self {
+        Self::ExtensionHeader(value)
+    }
+}
+
+impl From<Ipv6GetHeaderError> for Ipv6Error {
+    fn from(value: Ipv6GetHeaderError) -> Self {
+        Self::GetHeader(value)
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for Ipv6Error {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum Ipv6BpfCreationError {
+    InvalidVersion { version: u8 },
+    PacketTooShort { size: usize, expected: usize },
+    BpfOutOfBounds,
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for Ipv6BpfCreationError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+impl From<CursorError> for Ipv6BpfCreationError {
+    fn from(value: CursorError) -> Self {
+        match value {
+            CursorError::TooShort { size, expected } => Self::PacketTooShort { size, expected },
+            CursorError::BpfOutOfBounds => Self::BpfOutOfBounds,
+            CursorError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            CursorError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for Ipv6BpfCreationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidVersion { version } => {
+                write!(f, "IPv6 header expected version 6, was: {version}")
+            }
+            Self::PacketTooShort { size, expected } => {
+                write!(
+                    f,
+                    "IPv6 packet expected to be at least {expected} bytes, was: {size}"
+                )
+            }
+            Self::BpfOutOfBounds => {
+                write!(f, "Bpf out of bounds")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for Ipv6BpfCreationError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum Ipv6ExtensionHeaderError {
+    TruncatedChain {
+        size: usize,
+        expected: usize,
+    },
+    ChainTooDeep,
+    /// The packet is a fragment with a non-zero offset, `next_header` is the value of its
+    /// fragment header.
+    NonFirstFragment {
+        next_header: u8,
+    },
+    BpfOutOfBounds,
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for Ipv6ExtensionHeaderError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+impl From<CursorError> for Ipv6ExtensionHeaderError {
+    fn from(value: CursorError) -> Self {
+        match value {
+            CursorError::TooShort { size, expected } => Self::TruncatedChain { size, expected },
+            CursorError::BpfOutOfBounds => Self::BpfOutOfBounds,
+            CursorError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            CursorError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+impl From<Ipv6GetHeaderError> for Ipv6ExtensionHeaderError {
+    fn from(value: Ipv6GetHeaderError) -> Self {
+        match value {
+            Ipv6GetHeaderError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            Ipv6GetHeaderError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for Ipv6ExtensionHeaderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::TruncatedChain { size, expected } => {
+                write!(
+                    f,
+                    "IPv6 extension headers expected to be at least {expected} bytes, was: {size}"
+                )
+            }
+            Self::ChainTooDeep => {
+                write!(
+                    f,
+                    "IPv6 packet has more than {MAX_EXTENSION_HEADERS} extension headers"
+                )
+            }
+            Self::NonFirstFragment { next_header } => {
+                write!(
+                    f,
+                    "IPv6 packet is a non-first fragment without upper-layer header, next header: {next_header}"
+                )
+            }
+            Self::BpfOutOfBounds => {
+                write!(f, "Bpf out of bounds")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for Ipv6ExtensionHeaderError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum Ipv6GetHeaderError {
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for Ipv6GetHeaderError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for Ipv6GetHeaderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for Ipv6GetHeaderError {}
@@ -1,7 +1,32 @@
+//! Packet header parsers for eBPF programs.
+//!
+//! # Bounded loops
+//!
+//! The BPF verifier only accepts loops it can prove to terminate. Every loop over a variable
+//! amount of packet data, e.g. VLAN tags, IPv6 extension headers or options, is bounded by a
+//! `MAX_*` constant of its module. Type-length-value lists are walked by
+//! [`TlvIter`](tlv::TlvIter).
 #![forbid(unsafe_code)]
 #![no_std]
-#![feature(error_in_core)]
 
+pub mod arp;
+pub mod buffer;
+pub mod checksum;
+pub mod cursor;
 pub mod error;
 pub mod ethernet;
+pub mod geneve;
+pub mod gre;
+pub mod gtpu;
+pub mod icmp;
+pub mod icmpv6;
+pub mod ip;
+pub mod ipv4;
+pub mod ipv6;
 pub mod macros;
+pub mod mpls;
+pub mod pppoe;
+pub mod tcp;
+pub mod tlv;
+pub mod udp;
+pub mod vxlan;
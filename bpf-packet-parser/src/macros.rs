@@ -1,16 +1,106 @@
+/// Generates a `repr` enum with a lookup from its raw values.
+///
+/// Besides the listed variants the enum gets an error variant with `error_value`, which has to
+/// be a value no other variant uses. A variant can claim a range of values with
+/// `Variant = start..=end`, its discriminant is `start` then. Values without a variant are
+/// reported as `NoRecognized<Enum>` by `lookup`.
+///
+/// `lookup_table: true` looks values up in a table with one entry per representable value
+/// instead of matching them. Tables are only available for `u8` and `u16` reprs.
+///
+/// Variants listed in the optional `unmatched: { .. }` block are part of the enum, but are
+/// never returned by `lookup`. They are meant for values the caller derives itself, their
+/// values are reported as `NoRecognized<Enum>` like values without a variant.
 macro_rules! generate_matching_enum_impl {
+    (@last $value:literal) => {
+        $value
+    };
+    (@last $value:literal ..= $range_end:literal) => {
+        $range_end
+    };
+
+    (@table u8, $($rest:tt)*) => {
+        $crate::macros::generate_matching_enum_impl!(@table_impl u8, $($rest)*);
+    };
+    (@table u16, $($rest:tt)*) => {
+        $crate::macros::generate_matching_enum_impl!(@table_impl u16, $($rest)*);
+    };
+    (@table $repr_type:ident, true, $($rest:tt)*) => {
+        compile_error!("lookup tables are only supported for u8 and u16 reprs");
+    };
+    (@table $repr_type:ident, false, $($rest:tt)*) => {};
+    (
+        @table_impl $repr_type:ident, $lookup_table_indicator:tt, $enum_vis:vis, $enum_name:ident,
+        $table_name:ident, $error_variant:ident,
+        [$($variant_name:ident = $variant_value:literal $(..= $range_end:literal)?,)*]
+    ) => {
+        $enum_vis const $table_name: [$enum_name;<$repr_type>::MAX as usize + 1]
+            = $enum_name::generator();
+
+        impl $enum_name {
+            $enum_vis const fn generator() -> [$enum_name;<$repr_type>::MAX as usize + 1] {
+                // We use <$repr_type>::MAX + 1 as <$repr_type>::MAX is the max value and we need
+                // the amount of values that can be represented. +1 accounts for the 0.
+                let mut table = [$enum_name::$error_variant;<$repr_type>::MAX as usize + 1];
+                // Set all valid values, rest stays at $enum_name::$error_variant.
+                $(
+                    let mut value = $variant_value as usize;
+                    while value
+                        <= $crate::macros::generate_matching_enum_impl!(
+                            @last $variant_value $(..= $range_end)?
+                        ) as usize
+                    {
+                        table[value] = $enum_name::$variant_name;
+                        value += 1;
+                    }
+                )*
+                table
+            }
+        }
+    };
+
+    (
+        @lookup true, $value:ident, $enum_name:ident, $table_name:ident, $error_variant:ident,
+        $error_name:ident, $no_recognized:ident, $field:ident, [$($variants:tt)*]
+    ) => {{
+        let res = $table_name[$value as usize];
+        if res != $enum_name::$error_variant {
+            Ok(res)
+        } else {
+            Err($error_name::$no_recognized { $field: $value })
+        }
+    }};
+    (
+        @lookup false, $value:ident, $enum_name:ident, $table_name:ident, $error_variant:ident,
+        $error_name:ident, $no_recognized:ident, $field:ident,
+        [$($variant_name:ident = $variant_value:literal $(..= $range_end:literal)?,)*]
+    ) => {
+        match $value {
+            $($variant_value $(..= $range_end)? => Ok($enum_name::$variant_name),)*
+            _ => Err($error_name::$no_recognized { $field: $value }),
+        }
+    };
+
     (
         $(#[doc = $docs:literal])*
-        #[repr($repr_type:ty)]
+        #[repr($repr_type:ident)]
         $(#[$enum_meta:meta])*
         $enum_vis:vis enum $enum_name:ident {
             $(
                 $(#[$variant_meta:meta])*
-                $variant_name:ident = $variant_value:expr,
+                $variant_name:ident = $variant_value:literal $(..= $range_end:literal)?,
             )*
         }
         (error_value: $error_value:expr),
-        (lookup_table: $lookup_table_indicator:expr)
+        (lookup_table: $lookup_table_indicator:tt)
+        $(,
+            (unmatched: {
+                $(
+                    $(#[$unmatched_meta:meta])*
+                    $unmatched_name:ident = $unmatched_value:literal,
+                )*
+            })
+        )?
     ) => {
          paste::paste! {
             $(#[doc = $docs])*
@@ -21,6 +111,10 @@ macro_rules! generate_matching_enum_impl {
                     $(#[$variant_meta])*
                     $variant_name = $variant_value,
                 )*
+                $($(
+                    $(#[$unmatched_meta])*
+                    $unmatched_name = $unmatched_value,
+                )*)?
                 [<$enum_name:camel ErrorVariant>] = $error_value,
             }
 
@@ -31,38 +125,32 @@ macro_rules! generate_matching_enum_impl {
                 }
             }
 
-            $enum_vis const [<$enum_name:snake:upper _TABLE>]: [$enum_name;<$repr_type>::MAX as usize + 1]
-                = $enum_name::generator();
+            $crate::macros::generate_matching_enum_impl!(
+                @table $repr_type, $lookup_table_indicator, $enum_vis, $enum_name,
+                [<$enum_name:snake:upper _TABLE>], [<$enum_name:camel ErrorVariant>],
+                [$($variant_name = $variant_value $(..= $range_end)?,)*]
+            );
 
             impl $enum_name  {
                 #[cfg_attr(feature = "inline", inline(always))]
                 $enum_vis fn lookup(value: $repr_type) -> core::result::Result<Self, [<$enum_name ParsingError>]> {
-                    if $lookup_table_indicator {
-                        let res = [<$enum_name:snake:upper _TABLE>][value as usize];
-                        if res != $enum_name::[<$enum_name:camel ErrorVariant>] {
-                            Ok(res)
-                        } else{
-                            return Err([<$enum_name ParsingError>]::[<NoRecognized $enum_name>] {
-                                [<$enum_name:snake>]: value
-                            });
-                        }
-                    } else {
-                        match value {
-                            $($variant_value => Ok($enum_name::$variant_name),)*
-                            _ => Err([<$enum_name ParsingError>]::[<NoRecognized $enum_name>] {
-                                [<$enum_name:snake>]: value
-                            }),
-                        }
-                    }
+                    $crate::macros::generate_matching_enum_impl!(
+                        @lookup $lookup_table_indicator, value, $enum_name,
+                        [<$enum_name:snake:upper _TABLE>], [<$enum_name:camel ErrorVariant>],
+                        [<$enum_name ParsingError>], [<NoRecognized $enum_name>],
+                        [<$enum_name:snake>],
+                        [$($variant_name = $variant_value $(..= $range_end)?,)*]
+                    )
                 }
 
-                $enum_vis const fn generator() -> [$enum_name;(<$repr_type>::MAX as usize + 1) as usize] {
-                    // We use <$repr_type>::MAX + 1 as <$repr_type>::MAX is the max value and we need
-                    // the amount of values that can be represented. +1 accounts for the 0.
-                    let mut table = [$enum_name::[<$enum_name:camel ErrorVariant>];(<$repr_type>::MAX as usize + 1) as usize];
-                    // Set all valid values, rest stays at $enum_name::$error_variant.
-                    $(table[$variant_value] = $enum_name::$variant_name;)*
-                    table
+                /// Like `lookup`, but returns the error variant instead of an error for values
+                /// without a variant.
+                #[cfg_attr(feature = "inline", inline(always))]
+                $enum_vis fn lookup_lenient(value: $repr_type) -> Self {
+                    match Self::lookup(value) {
+                        Ok(res) => res,
+                        Err(_) => $enum_name::[<$enum_name:camel ErrorVariant>],
+                    }
                 }
             }
 
@@ -77,7 +165,11 @@ macro_rules! generate_matching_enum_impl {
                 fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                     match self {
                         Self::[<NoRecognized $enum_name>]{ [<$enum_name:snake>] } => {
-                            write!(f, "No valid ether type, was: {:?}", [<$enum_name:snake>])
+                            write!(
+                                f,
+                                concat!("No valid ", stringify!($enum_name), ", was: {:?}"),
+                                [<$enum_name:snake>]
+                            )
                         }
                     }
                 }
@@ -86,6 +178,57 @@ macro_rules! generate_matching_enum_impl {
             #[cfg(feature = "error_trait")]
             impl core::error::Error for [<$enum_name ParsingError>] {}
         }
-    }
+    };
 }
 pub(crate) use generate_matching_enum_impl;
+
+#[cfg(all(test, feature = "fmt"))]
+mod tests {
+    generate_matching_enum_impl! {
+        /// A `u32` enum with values beyond `u16::MAX`, only matched as `u32` has no table.
+        #[repr(u32)]
+        // Only linted for enums which aren't exported, like this one.
+        #[allow(clippy::enum_variant_names)]
+        #[cfg_attr(feature = "fmt", derive(Debug))]
+        #[derive(Copy, Clone, PartialEq, Eq)]
+        pub enum Wide {
+            Small = 1,
+            Large = 0x0001_0000,
+            Range = 0x00A0_0000..=0x00A0_00FF,
+        }
+        (error_value: 0xFFFF_FFFF),
+        (lookup_table: false),
+        (unmatched: {
+            Derived = 0x0002_0000,
+        })
+    }
+
+    #[test]
+    fn lookup_u32() {
+        assert_eq!(Wide::lookup(1), Ok(Wide::Small));
+        assert_eq!(Wide::lookup(0x0001_0000), Ok(Wide::Large));
+        assert_eq!(Wide::lookup(0x00A0_0000), Ok(Wide::Range));
+        assert_eq!(Wide::lookup(0x00A0_00FF), Ok(Wide::Range));
+        assert_eq!(
+            Wide::lookup(0x00A0_0100),
+            Err(WideParsingError::NoRecognizedWide { wide: 0x00A0_0100 })
+        );
+        assert_eq!(Wide::Range as u32, 0x00A0_0000);
+    }
+
+    #[test]
+    fn lookup_u32_unmatched() {
+        assert_eq!(
+            Wide::lookup(0x0002_0000),
+            Err(WideParsingError::NoRecognizedWide { wide: 0x0002_0000 })
+        );
+        assert_eq!(Wide::Derived as u32, 0x0002_0000);
+    }
+
+    #[test]
+    fn lookup_lenient_u32() {
+        assert_eq!(Wide::lookup_lenient(0x0001_0000), Wide::Large);
+        assert_eq!(Wide::lookup_lenient(0), Wide::WideErrorVariant);
+        assert_eq!(Wide::WideErrorVariant as u32, 0xFFFF_FFFF);
+    }
+}
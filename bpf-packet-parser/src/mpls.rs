@@ -0,0 +1,185 @@
+mod error;
+mod header_values;
+mod label;
+
+pub use error::*;
+pub use header_values::*;
+pub use label::*;
+
+use crate::buffer::PacketBuffer;
+use crate::cursor::PacketCursor;
+#[cfg(feature = "fmt")]
+use core::fmt::Debug;
+
+pub static MPLS_LABEL_SIZE: usize = 4;
+
+/// Default maximum amount of labels walked by [`MplsStack::from_cursor`].
+pub const MAX_MPLS_LABELS: usize = 8;
+
+/// MPLS label stack, found in the payload of frames with [`EtherType::MplsUnicast`] and
+/// [`EtherType::MplsMulticast`].
+///
+/// [`EtherType::MplsUnicast`]: crate::ethernet::EtherType::MplsUnicast
+/// [`EtherType::MplsMulticast`]: crate::ethernet::EtherType::MplsMulticast
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(PartialEq, Eq)]
+pub struct MplsStack<B> {
+    // size of 4B per label
+    stack: B,
+}
+
+impl<B: PacketBuffer> MplsStack<B> {
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn new_bpf(buf: B, end: usize) -> Result<(Self, B), MplsBpfCreationError> {
+        let mut cursor = PacketCursor::new(buf, end);
+        let stack = Self::from_cursor(&mut cursor)?;
+        Ok((stack, cursor.into_inner()))
+    }
+
+    /// Takes the label stack up to and including the bottom of stack entry from the front of
+    /// `cursor` and leaves the cursor at the payload. Stacks with more than
+    /// [`MAX_MPLS_LABELS`] labels fail, see [`MplsStack::from_cursor_with_depth`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_cursor(cursor: &mut PacketCursor<B>) -> Result<Self, MplsBpfCreationError> {
+        Self::from_cursor_with_depth::<MAX_MPLS_LABELS>(cursor)
+    }
+
+    /// Like [`MplsStack::from_cursor`], but walks at most `MAX_LABELS` labels.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_cursor_with_depth<const MAX_LABELS: usize>(
+        cursor: &mut PacketCursor<B>,
+    ) -> Result<Self, MplsBpfCreationError> {
+        // We read 4 bytes for every label:
+        // 20 bits label
+        // 3 bits traffic class
+        // 1 bit bottom of stack
+        // 8 bits TTL
+        // ------------------
+        // 4 bytes total
+
+        let mut stack_len = 0;
+        for _ in 0..MAX_LABELS {
+            let label = MplsLabel::from_bytes(cursor.peek_at::<4>(stack_len)?);
+            stack_len += MPLS_LABEL_SIZE;
+
+            if label.is_bottom_of_stack() {
+                let stack = cursor.split_header_len(stack_len)?;
+                return Ok(MplsStack { stack });
+            }
+        }
+
+        Err(MplsBpfCreationError::StackTooDeep {
+            max_labels: MAX_LABELS,
+        })
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_label_count(&self) -> usize {
+        self.stack.len() / MPLS_LABEL_SIZE
+    }
+
+    /// Returns the label at `index`, 0 is the top of the stack.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_label(&self, index: usize) -> Result<MplsLabel, MplsGetHeaderError> {
+        let start = index * MPLS_LABEL_SIZE;
+        Ok(MplsLabel::from_bytes(
+            self.stack
+                .get(start..start + MPLS_LABEL_SIZE)
+                .ok_or(MplsGetHeaderError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        ))
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_top_label(&self) -> Result<MplsLabel, MplsGetHeaderError> {
+        self.get_label(0)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_bottom_label(&self) -> Result<MplsLabel, MplsGetHeaderError> {
+        self.get_label(self.get_label_count().wrapping_sub(1))
+    }
+
+    /// Guesses the payload in `cursor`, which has to be left behind the stack by
+    /// [`MplsStack::from_cursor`], see [`guess_payload_type`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_payload_type(
+        &self,
+        cursor: &PacketCursor<B>,
+    ) -> Result<MplsPayloadType, MplsPayloadTypeError> {
+        let [first_byte] = *cursor.peek::<1>()?;
+        Ok(guess_payload_type(self.get_bottom_label()?, first_byte))
+    }
+}
+
+/// Guesses the payload behind `bottom_label` from its first byte. The IPv4 and IPv6 explicit
+/// null labels decide on their own, all other labels are decided by the first nibble.
+/// Unknown nibbles are returned as [`MplsPayloadType::MplsPayloadTypeErrorVariant`].
+#[cfg_attr(feature = "inline", inline(always))]
+pub fn guess_payload_type(bottom_label: MplsLabel, first_byte: u8) -> MplsPayloadType {
+    let label = bottom_label.get_label();
+    if label == MPLS_LABEL_IPV4_EXPLICIT_NULL {
+        MplsPayloadType::Ipv4
+    } else if label == MPLS_LABEL_IPV6_EXPLICIT_NULL {
+        MplsPayloadType::Ipv6
+    } else {
+        MplsPayloadType::lookup_lenient(first_byte >> 4)
+    }
+}
+
+#[cfg(all(test, feature = "fmt"))]
+mod tests {
+    use super::*;
+
+    // Three labels, the last one with the bottom of stack bit, followed by an IPv4 header.
+    const STACK: [u8; 13] = [
+        0x00, 0x06, 0x40, 0x40, 0x00, 0x0C, 0x80, 0x40, 0x00, 0x12, 0xC1, 0x40, 0x45,
+    ];
+
+    type Parsed<'a> = (MplsStack<&'a [u8]>, PacketCursor<&'a [u8]>);
+
+    fn parse<const MAX_LABELS: usize>(buf: &[u8]) -> Result<Parsed<'_>, MplsBpfCreationError> {
+        let end = buf.as_ptr() as usize + buf.len();
+        let mut cursor = PacketCursor::new(buf, end);
+        let stack = MplsStack::from_cursor_with_depth::<MAX_LABELS>(&mut cursor)?;
+        Ok((stack, cursor))
+    }
+
+    #[test]
+    fn from_cursor_stops_at_bottom_of_stack() {
+        let (stack, cursor) = parse::<MAX_MPLS_LABELS>(&STACK).unwrap();
+        assert_eq!(stack.get_label_count(), 3);
+        assert_eq!(
+            stack.get_top_label().map(|label| label.get_label()),
+            Ok(100)
+        );
+        assert_eq!(
+            stack.get_bottom_label().map(|label| label.get_label()),
+            Ok(300)
+        );
+        assert!(!stack.get_label(1).unwrap().is_bottom_of_stack());
+        assert!(stack.get_bottom_label().unwrap().is_bottom_of_stack());
+        assert_eq!(stack.get_payload_type(&cursor), Ok(MplsPayloadType::Ipv4));
+        assert_eq!(cursor.into_inner(), &[0x45]);
+    }
+
+    #[test]
+    fn from_cursor_with_depth_cap() {
+        assert_eq!(
+            parse::<3>(&STACK).map(|(stack, _)| stack.get_label_count()),
+            Ok(3)
+        );
+        assert_eq!(
+            parse::<2>(&STACK).map(|(stack, _)| stack.get_label_count()),
+            Err(MplsBpfCreationError::StackTooDeep { max_labels: 2 })
+        );
+    }
+
+    #[test]
+    fn from_cursor_without_bottom_of_stack() {
+        assert_eq!(
+            parse::<MAX_MPLS_LABELS>(&STACK[..8]).map(|(stack, _)| stack.get_label_count()),
+            Err(MplsBpfCreationError::BpfOutOfBounds)
+        );
+    }
+}
@@ -0,0 +1,194 @@
+use crate::cursor::CursorError;
+use core::array::TryFromSliceError;
+#[cfg(feature = "fmt")]
+use core::fmt::{Debug, Display, Formatter};
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum MplsError {
+    BpfCreation(MplsBpfCreationError),
+    GetHeader(MplsGetHeaderError),
+    PayloadType(MplsPayloadTypeError),
+}
+
+#[cfg(feature = "fmt")]
+impl Display for MplsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MplsError::BpfCreation(err) => {
+                write!(f, "{err}")
+            }
+            MplsError::GetHeader(err) => {
+                write!(f, "{err}")
+            }
+            MplsError::PayloadType(err) => {
+                write!(f, "{err}")
+            }
+        }
+    }
+}
+
+impl From<MplsBpfCreationError> for MplsError {
+    fn from(value: MplsBpfCreationError) -> Self {
+        Self::BpfCreation(value)
+    }
+}
+
+impl From<MplsGetHeaderError> for MplsError {
+    fn from(value: MplsGetHeaderError) -> Self {
+        Self::GetHeader(value)
+    }
+}
+
+impl From<MplsPayloadTypeError> for MplsError {
+    fn from(value: MplsPayloadTypeError) -> Self {
+        Self::PayloadType(value)
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for MplsError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum MplsBpfCreationError {
+    StackTooDeep { max_labels: usize },
+    StackTooShort { size: usize, expected: usize },
+    BpfOutOfBounds,
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for MplsBpfCreationError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+impl From<CursorError> for MplsBpfCreationError {
+    fn from(value: CursorError) -> Self {
+        match value {
+            CursorError::TooShort { size, expected } => Self::StackTooShort { size, expected },
+            CursorError::BpfOutOfBounds => Self::BpfOutOfBounds,
+            CursorError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            CursorError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for MplsBpfCreationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::StackTooDeep { max_labels } => {
+                write!(
+                    f,
+                    "No bottom of stack within the first {max_labels} MPLS labels"
+                )
+            }
+            Self::StackTooShort { size, expected } => {
+                write!(
+                    f,
+                    "MPLS label stack expected to be at least {expected} bytes, was: {size}"
+                )
+            }
+            Self::BpfOutOfBounds => {
+                write!(f, "Bpf out of bounds")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for MplsBpfCreationError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum MplsGetHeaderError {
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for MplsGetHeaderError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for MplsGetHeaderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for MplsGetHeaderError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum MplsPayloadTypeError {
+    PayloadTooShort { size: usize, expected: usize },
+    BpfOutOfBounds,
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<MplsGetHeaderError> for MplsPayloadTypeError {
+    fn from(value: MplsGetHeaderError) -> Self {
+        match value {
+            MplsGetHeaderError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            MplsGetHeaderError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+impl From<CursorError> for MplsPayloadTypeError {
+    fn from(value: CursorError) -> Self {
+        match value {
+            CursorError::TooShort { size, expected } => Self::PayloadTooShort { size, expected },
+            CursorError::BpfOutOfBounds => Self::BpfOutOfBounds,
+            CursorError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            CursorError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for MplsPayloadTypeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::PayloadTooShort { size, expected } => {
+                write!(
+                    f,
+                    "MPLS payload expected to be at least {expected} bytes, was: {size}"
+                )
+            }
+            Self::BpfOutOfBounds => {
+                write!(f, "Bpf out of bounds")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for MplsPayloadTypeError {}
@@ -0,0 +1,21 @@
+crate::macros::generate_matching_enum_impl! {
+    /// Payload behind the bottom of the label stack, guessed from its first nibble.
+    /// MPLS itself doesn't carry the payload protocol.
+    ///
+    /// Sources:
+    /// `<https://www.rfc-editor.org/rfc/rfc4385>`
+    /// `<https://www.rfc-editor.org/rfc/rfc5586>`
+    #[repr(u8)]
+    #[cfg_attr(feature = "fmt", derive(Debug))]
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub enum MplsPayloadType {
+        /// Pseudowire with control word
+        PseudowireControlWord = 0,
+        /// Generic associated channel (G-ACh)
+        AssociatedChannel = 1,
+        Ipv4 = 4,
+        Ipv6 = 6,
+    }
+    (error_value: 0xFF),
+    (lookup_table: false)
+}
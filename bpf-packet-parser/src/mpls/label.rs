@@ -0,0 +1,91 @@
+#[cfg(feature = "fmt")]
+use core::fmt::Debug;
+
+/// Reserved label values, see RFC 3032 and RFC 7274.
+pub static MPLS_LABEL_IPV4_EXPLICIT_NULL: u32 = 0;
+pub static MPLS_LABEL_ROUTER_ALERT: u32 = 1;
+pub static MPLS_LABEL_IPV6_EXPLICIT_NULL: u32 = 2;
+pub static MPLS_LABEL_IMPLICIT_NULL: u32 = 3;
+pub static MPLS_LABEL_ENTROPY_LABEL_INDICATOR: u32 = 7;
+pub static MPLS_LABEL_GAL: u32 = 13;
+pub static MPLS_LABEL_OAM_ALERT: u32 = 14;
+pub static MPLS_LABEL_EXTENSION: u32 = 15;
+
+/// A decoded 4 byte MPLS label stack entry: 20 bits label, 3 bits traffic class, 1 bit bottom
+/// of stack and 8 bits TTL.
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct MplsLabel {
+    entry: u32,
+}
+
+impl MplsLabel {
+    /// Creates a new entry, `label` is truncated to 20 bits and `tc` to 3 bits.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn new(label: u32, tc: u8, bottom_of_stack: bool, ttl: u8) -> Self {
+        MplsLabel {
+            entry: ((label & 0x000F_FFFF) << 12)
+                | ((tc as u32 & 0x07) << 9)
+                | ((bottom_of_stack as u32) << 8)
+                | ttl as u32,
+        }
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_bytes(bytes: &[u8; 4]) -> Self {
+        MplsLabel {
+            entry: u32::from_be_bytes(*bytes),
+        }
+    }
+
+    /// Returns the entry as it is laid out on the wire.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn to_bytes(&self) -> [u8; 4] {
+        self.entry.to_be_bytes()
+    }
+
+    /// The whole label stack entry.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_entry(&self) -> u32 {
+        self.entry
+    }
+
+    /// Label value, 20 bits.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_label(&self) -> u32 {
+        self.entry >> 12
+    }
+
+    /// Traffic class, formerly EXP, 3 bits.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_tc(&self) -> u8 {
+        ((self.entry >> 9) & 0x07) as u8
+    }
+
+    /// Set on the last entry of the label stack.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn is_bottom_of_stack(&self) -> bool {
+        self.entry & 0x0100 != 0
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_ttl(&self) -> u8 {
+        self.entry as u8
+    }
+
+    /// Returns a copy of the entry with the bottom of stack bit set to `bottom_of_stack`.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn with_bottom_of_stack(&self, bottom_of_stack: bool) -> Self {
+        MplsLabel {
+            entry: (self.entry & !0x0100) | ((bottom_of_stack as u32) << 8),
+        }
+    }
+
+    /// Returns a copy of the entry with the TTL set to `ttl`.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn with_ttl(&self, ttl: u8) -> Self {
+        MplsLabel {
+            entry: (self.entry & !0xFF) | ttl as u32,
+        }
+    }
+}
@@ -0,0 +1,252 @@
+mod error;
+mod header_values;
+mod tags;
+
+pub use error::*;
+pub use header_values::*;
+pub use tags::*;
+
+use crate::buffer::PacketBuffer;
+use crate::cursor::PacketCursor;
+#[cfg(feature = "fmt")]
+use core::fmt::Debug;
+
+// All ranges are [start..end).
+// This means start is included, end is not included.
+pub static VERSION_TYPE: usize = 0;
+pub static CODE: usize = 1;
+pub static SESSION_ID_START: usize = 2;
+pub static SESSION_ID_END: usize = 4;
+pub static LENGTH_START: usize = 4;
+pub static LENGTH_END: usize = 6;
+pub static PPP_PROTOCOL_START: usize = 6;
+pub static PPP_PROTOCOL_END: usize = 8;
+pub static TAGS_START: usize = 6;
+
+pub static PPPOE_HEADER_SIZE: usize = 6;
+pub static PPP_PROTOCOL_SIZE: usize = 2;
+/// Version 1 and type 1, the only combination defined by RFC 2516.
+pub static PPPOE_VERSION_TYPE: u8 = 0x11;
+
+/// PPPoE session header followed by the PPP protocol field, found in the payload of frames
+/// with [`EtherType::PppoeSessionStage`].
+///
+/// The payload stays in the cursor, including Ethernet padding behind the PPP packet, see
+/// [`PppoeSessionHeader::get_payload_len`] and [`PacketCursor::truncate`].
+///
+/// [`EtherType::PppoeSessionStage`]: crate::ethernet::EtherType::PppoeSessionStage
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(PartialEq, Eq)]
+pub struct PppoeSessionHeader<B> {
+    // size of 8B
+    header: B,
+}
+
+impl<B: PacketBuffer> PppoeSessionHeader<B> {
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn new_bpf(buf: B, end: usize) -> Result<(Self, B), PppoeBpfCreationError> {
+        let mut cursor = PacketCursor::new(buf, end);
+        let header = Self::from_cursor(&mut cursor)?;
+        Ok((header, cursor.into_inner()))
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_cursor(cursor: &mut PacketCursor<B>) -> Result<Self, PppoeBpfCreationError> {
+        // We read the first 8 bytes of the packet:
+        // 4 bits version
+        // 4 bits type
+        // 1 byte code
+        // 2 bytes session ID
+        // 2 bytes length
+        // 2 bytes PPP protocol
+        // ------------------
+        // 8 bytes total
+
+        let (code, length) = check_header(cursor)?;
+        if code != PppoeCode::SessionData as u8 {
+            return Err(PppoeBpfCreationError::WrongCode { code });
+        }
+        if (length as usize) < PPP_PROTOCOL_SIZE {
+            return Err(PppoeBpfCreationError::InvalidLength { length });
+        }
+
+        let header = cursor.split_header::<8>()?;
+
+        Ok(PppoeSessionHeader { header })
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_version(&self) -> Result<u8, PppoeGetHeaderError> {
+        Ok(get_u8(&self.header, VERSION_TYPE)? >> 4)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_type(&self) -> Result<u8, PppoeGetHeaderError> {
+        Ok(get_u8(&self.header, VERSION_TYPE)? & 0x0F)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_code(&self) -> Result<u8, PppoeGetHeaderError> {
+        get_u8(&self.header, CODE)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_session_id(&self) -> Result<u16, PppoeGetHeaderError> {
+        get_u16(&self.header, SESSION_ID_START, SESSION_ID_END)
+    }
+
+    /// Length of the PPP packet, PPP protocol field included.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_length(&self) -> Result<u16, PppoeGetHeaderError> {
+        get_u16(&self.header, LENGTH_START, LENGTH_END)
+    }
+
+    /// Length of the PPP payload behind the PPP protocol field.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_payload_len(&self) -> Result<usize, PppoeGetHeaderError> {
+        Ok(self.get_length()? as usize - PPP_PROTOCOL_SIZE)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_ppp_protocol(&self) -> Result<u16, PppoeGetHeaderError> {
+        get_u16(&self.header, PPP_PROTOCOL_START, PPP_PROTOCOL_END)
+    }
+
+    /// The PPP protocol as [`PppProtocol`], unknown values are returned as
+    /// [`PppProtocol::PppProtocolErrorVariant`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_typed_ppp_protocol(&self) -> Result<PppProtocol, PppoeGetHeaderError> {
+        Ok(PppProtocol::lookup_lenient(self.get_ppp_protocol()?))
+    }
+}
+
+/// PPPoE discovery packet including its tags, found in the payload of frames with
+/// [`EtherType::PppoeDiscoveryStage`].
+///
+/// [`EtherType::PppoeDiscoveryStage`]: crate::ethernet::EtherType::PppoeDiscoveryStage
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(PartialEq, Eq)]
+pub struct PppoeDiscoveryHeader<B> {
+    // size of 6B + length
+    header: B,
+}
+
+impl<B: PacketBuffer> PppoeDiscoveryHeader<B> {
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn new_bpf(buf: B, end: usize) -> Result<(Self, B), PppoeBpfCreationError> {
+        let mut cursor = PacketCursor::new(buf, end);
+        let header = Self::from_cursor(&mut cursor)?;
+        Ok((header, cursor.into_inner()))
+    }
+
+    /// Takes the header and `length` bytes of tags from the front of `cursor`. Ethernet
+    /// padding behind the tags stays in the cursor.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_cursor(cursor: &mut PacketCursor<B>) -> Result<Self, PppoeBpfCreationError> {
+        // We read the first 6 bytes of the packet:
+        // 4 bits version
+        // 4 bits type
+        // 1 byte code
+        // 2 bytes session ID
+        // 2 bytes length
+        // ------------------
+        // 6 bytes total
+        // The tags take length bytes.
+
+        let (code, length) = check_header(cursor)?;
+        if code == PppoeCode::SessionData as u8 {
+            return Err(PppoeBpfCreationError::WrongCode { code });
+        }
+
+        let header = cursor.split_header_len(PPPOE_HEADER_SIZE + length as usize)?;
+
+        Ok(PppoeDiscoveryHeader { header })
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_version(&self) -> Result<u8, PppoeGetHeaderError> {
+        Ok(get_u8(&self.header, VERSION_TYPE)? >> 4)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_type(&self) -> Result<u8, PppoeGetHeaderError> {
+        Ok(get_u8(&self.header, VERSION_TYPE)? & 0x0F)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_code(&self) -> Result<u8, PppoeGetHeaderError> {
+        get_u8(&self.header, CODE)
+    }
+
+    /// The code as [`PppoeCode`], unassigned values are returned as
+    /// [`PppoeCode::PppoeCodeErrorVariant`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_typed_code(&self) -> Result<PppoeCode, PppoeGetHeaderError> {
+        Ok(PppoeCode::lookup_lenient(self.get_code()?))
+    }
+
+    /// 0 for PADI, PADO and PADR, the session ID of PADS and later packets.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_session_id(&self) -> Result<u16, PppoeGetHeaderError> {
+        get_u16(&self.header, SESSION_ID_START, SESSION_ID_END)
+    }
+
+    /// Length of the tags.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_length(&self) -> Result<u16, PppoeGetHeaderError> {
+        get_u16(&self.header, LENGTH_START, LENGTH_END)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn tags(&self) -> PppoeTagsIter<'_> {
+        PppoeTagsIter::new(self.header.get(TAGS_START..).unwrap_or_default())
+    }
+}
+
+/// Checks version and type and that `length` bytes follow the header. Returns code and
+/// length.
+#[cfg_attr(feature = "inline", inline(always))]
+fn check_header<B: PacketBuffer>(
+    cursor: &PacketCursor<B>,
+) -> Result<(u8, u16), PppoeBpfCreationError> {
+    let header = cursor.peek::<6>()?;
+    let version_type = *header
+        .get(VERSION_TYPE)
+        .ok_or(PppoeBpfCreationError::OutOfBoundsBufferAccess)?;
+    if version_type != PPPOE_VERSION_TYPE {
+        return Err(PppoeBpfCreationError::InvalidVersionType { version_type });
+    }
+    let code = *header
+        .get(CODE)
+        .ok_or(PppoeBpfCreationError::OutOfBoundsBufferAccess)?;
+    let length = u16::from_be_bytes(
+        header
+            .get(LENGTH_START..LENGTH_END)
+            .ok_or(PppoeBpfCreationError::OutOfBoundsBufferAccess)?
+            .try_into()?,
+    );
+
+    let size = cursor.len() - PPPOE_HEADER_SIZE;
+    if length as usize > size {
+        return Err(PppoeBpfCreationError::LengthExceedsPayload { length, size });
+    }
+    Ok((code, length))
+}
+
+#[cfg_attr(feature = "inline", inline(always))]
+fn get_u8(header: &[u8], index: usize) -> Result<u8, PppoeGetHeaderError> {
+    header
+        .get(index)
+        .copied()
+        .ok_or(PppoeGetHeaderError::OutOfBoundsBufferAccess)
+}
+
+#[cfg_attr(feature = "inline", inline(always))]
+fn get_u16(header: &[u8], start: usize, end: usize) -> Result<u16, PppoeGetHeaderError> {
+    Ok(u16::from_be_bytes(
+        header
+            .get(start..end)
+            .ok_or(PppoeGetHeaderError::OutOfBoundsBufferAccess)?
+            .try_into()?,
+    ))
+}
@@ -0,0 +1,194 @@
+use crate::cursor::CursorError;
+use core::array::TryFromSliceError;
+#[cfg(feature = "fmt")]
+use core::fmt::{Debug, Display, Formatter};
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum PppoeError {
+    BpfCreation(PppoeBpfCreationError),
+    GetHeader(PppoeGetHeaderError),
+    Tag(PppoeTagError),
+}
+
+#[cfg(feature = "fmt")]
+impl Display for PppoeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PppoeError::BpfCreation(err) => {
+                write!(f, "{err}")
+            }
+            PppoeError::GetHeader(err) => {
+                write!(f, "{err}")
+            }
+            PppoeError::Tag(err) => {
+                write!(f, "{err}")
+            }
+        }
+    }
+}
+
+impl From<PppoeBpfCreationError> for PppoeError {
+    fn from(value: PppoeBpfCreationError) -> Self {
+        Self::BpfCreation(value)
+    }
+}
+
+impl From<PppoeGetHeaderError> for PppoeError {
+    fn from(value: PppoeGetHeaderError) -> Self {
+        Self::GetHeader(value)
+    }
+}
+
+impl From<PppoeTagError> for PppoeError {
+    fn from(value: PppoeTagError) -> Self {
+        Self::Tag(value)
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for PppoeError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum PppoeBpfCreationError {
+    InvalidVersionType { version_type: u8 },
+    WrongCode { code: u8 },
+    InvalidLength { length: u16 },
+    LengthExceedsPayload { length: u16, size: usize },
+    PacketTooShort { size: usize, expected: usize },
+    BpfOutOfBounds,
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for PppoeBpfCreationError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+impl From<CursorError> for PppoeBpfCreationError {
+    fn from(value: CursorError) -> Self {
+        match value {
+            CursorError::TooShort { size, expected } => Self::PacketTooShort { size, expected },
+            CursorError::BpfOutOfBounds => Self::BpfOutOfBounds,
+            CursorError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            CursorError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for PppoeBpfCreationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidVersionType { version_type } => {
+                write!(
+                    f,
+                    "PPPoE version and type expected to be 0x11, was: {version_type:#04X}"
+                )
+            }
+            Self::WrongCode { code } => {
+                write!(f, "PPPoE code doesn't match the stage, was: {code:#04X}")
+            }
+            Self::InvalidLength { length } => {
+                write!(
+                    f,
+                    "PPPoE session length expected to be at least 2, was: {length}"
+                )
+            }
+            Self::LengthExceedsPayload { length, size } => {
+                write!(
+                    f,
+                    "PPPoE length {length} exceeds the {size} bytes left in the packet"
+                )
+            }
+            Self::PacketTooShort { size, expected } => {
+                write!(
+                    f,
+                    "PPPoE packet expected to be at least {expected} bytes, was: {size}"
+                )
+            }
+            Self::BpfOutOfBounds => {
+                write!(f, "Bpf out of bounds")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for PppoeBpfCreationError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum PppoeGetHeaderError {
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for PppoeGetHeaderError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for PppoeGetHeaderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for PppoeGetHeaderError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum PppoeTagError {
+    TruncatedHeader { size: usize },
+    Truncated { tag_type: u16, length: u16 },
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for PppoeTagError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for PppoeTagError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::TruncatedHeader { size } => {
+                write!(f, "PPPoE tag header expected to be 4 bytes, was: {size}")
+            }
+            Self::Truncated { tag_type, length } => {
+                write!(
+                    f,
+                    "PPPoE tag {tag_type:#06X} with length {length} exceeds the tags"
+                )
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for PppoeTagError {}
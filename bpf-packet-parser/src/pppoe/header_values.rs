@@ -0,0 +1,91 @@
+crate::macros::generate_matching_enum_impl! {
+    /// Sources:
+    /// `<https://www.iana.org/assignments/pppoe-parameters/pppoe-parameters.xhtml>`
+    #[repr(u8)]
+    #[cfg_attr(feature = "fmt", derive(Debug))]
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub enum PppoeCode {
+        SessionData = 0x00,
+        /// PPPoE Active Discovery Offer
+        Pado = 0x07,
+        /// PPPoE Active Discovery Initiation
+        Padi = 0x09,
+        /// PPPoE Active Discovery Request
+        Padr = 0x19,
+        /// PPPoE Active Discovery Session-confirmation
+        Pads = 0x65,
+        /// PPPoE Active Discovery Terminate
+        Padt = 0xA7,
+        /// PPPoE Active Discovery Message
+        Padm = 0xD3,
+        /// PPPoE Active Discovery Network
+        Padn = 0xD4,
+    }
+    (error_value: 0xFF),
+    (lookup_table: false)
+}
+
+crate::macros::generate_matching_enum_impl! {
+    /// Sources:
+    /// `<https://www.iana.org/assignments/pppoe-parameters/pppoe-parameters.xhtml>`
+    #[repr(u16)]
+    #[cfg_attr(feature = "fmt", derive(Debug))]
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub enum PppoeTagType {
+        EndOfList = 0x0000,
+        ServiceName = 0x0101,
+        AcName = 0x0102,
+        HostUniq = 0x0103,
+        AcCookie = 0x0104,
+        VendorSpecific = 0x0105,
+        Credits = 0x0106,
+        Metrics = 0x0107,
+        SequenceNumber = 0x0108,
+        CreditScaleFactor = 0x0109,
+        RelaySessionId = 0x0110,
+        Hurl = 0x0111,
+        Motm = 0x0112,
+        PppMaxPayload = 0x0120,
+        IpRouteAdd = 0x0121,
+        ServiceNameError = 0x0201,
+        AcSystemError = 0x0202,
+        GenericError = 0x0203,
+    }
+    (error_value: 0xFFFF),
+    (lookup_table: false)
+}
+
+crate::macros::generate_matching_enum_impl! {
+    /// Sources:
+    /// `<https://www.iana.org/assignments/ppp-numbers/ppp-numbers.xhtml>`
+    #[repr(u16)]
+    #[cfg_attr(feature = "fmt", derive(Debug))]
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub enum PppProtocol {
+        Padding = 0x0001,
+        Ipv4 = 0x0021,
+        Ipv6 = 0x0057,
+        MplsUnicast = 0x0281,
+        MplsMulticast = 0x0283,
+        /// Internet Protocol Control Protocol
+        Ipcp = 0x8021,
+        /// IPv6 Control Protocol
+        Ipv6cp = 0x8057,
+        /// Compression Control Protocol
+        Ccp = 0x80FD,
+        /// MPLS Control Protocol
+        Mplscp = 0x8281,
+        /// Link Control Protocol
+        Lcp = 0xC021,
+        /// Password Authentication Protocol
+        Pap = 0xC023,
+        /// Link Quality Report
+        Lqr = 0xC025,
+        /// Challenge Handshake Authentication Protocol
+        Chap = 0xC223,
+        /// Extensible Authentication Protocol
+        Eap = 0xC227,
+    }
+    (error_value: 0xFFFF),
+    (lookup_table: false)
+}
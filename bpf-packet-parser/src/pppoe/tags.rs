@@ -0,0 +1,99 @@
+use crate::pppoe::{PppoeTagError, PppoeTagType};
+use crate::tlv::{TlvIter, TlvParser};
+#[cfg(feature = "fmt")]
+use core::fmt::Debug;
+
+pub static PPPOE_TAG_HEADER_SIZE: usize = 4;
+pub const MAX_PPPOE_TAGS: usize = 16;
+
+/// A tag of a PPPoE discovery packet: 2 bytes type, 2 bytes length and the value.
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct PppoeTag<'a> {
+    tag_type: u16,
+    value: &'a [u8],
+}
+
+impl<'a> PppoeTag<'a> {
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_tag_type(&self) -> u16 {
+        self.tag_type
+    }
+
+    /// The tag type as [`PppoeTagType`], unassigned values are returned as
+    /// [`PppoeTagType::PppoeTagTypeErrorVariant`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_typed_tag_type(&self) -> PppoeTagType {
+        PppoeTagType::lookup_lenient(self.tag_type)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_value(&self) -> &'a [u8] {
+        self.value
+    }
+}
+
+/// Iterator over the tags of a PPPoE discovery packet, stops at an End-Of-List tag.
+pub type PppoeTagsIter<'a> = TlvIter<'a, PppoeTagParser>;
+
+impl<'a> PppoeTagsIter<'a> {
+    /// Returns the first tag with type `tag_type`, e.g. the Host-Uniq tag to match a PADO to
+    /// its PADI.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn find_tag(
+        mut self,
+        tag_type: PppoeTagType,
+    ) -> Option<Result<PppoeTag<'a>, PppoeTagError>> {
+        for _ in 0..MAX_PPPOE_TAGS {
+            match self.next()? {
+                Ok(tag) if tag.get_tag_type() != tag_type as u16 => {}
+                res => return Some(res),
+            }
+        }
+        None
+    }
+}
+
+/// Parses the tags of a [`PppoeTagsIter`].
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Default)]
+pub struct PppoeTagParser;
+
+impl<'a> TlvParser<'a> for PppoeTagParser {
+    type Item = PppoeTag<'a>;
+    type Error = PppoeTagError;
+
+    const MAX: usize = MAX_PPPOE_TAGS;
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn parse(
+        &mut self,
+        tags: &'a [u8],
+        offset: &mut usize,
+    ) -> Option<Result<PppoeTag<'a>, PppoeTagError>> {
+        match parse_tag(tags, offset) {
+            Ok(tag) if tag.get_tag_type() == PppoeTagType::EndOfList as u16 => None,
+            tag => Some(tag),
+        }
+    }
+}
+
+#[cfg_attr(feature = "inline", inline(always))]
+fn parse_tag<'a>(tags: &'a [u8], offset: &mut usize) -> Result<PppoeTag<'a>, PppoeTagError> {
+    let header: &[u8; 4] = tags
+        .get(*offset..*offset + PPPOE_TAG_HEADER_SIZE)
+        .ok_or(PppoeTagError::TruncatedHeader {
+            size: tags.len() - *offset,
+        })?
+        .try_into()?;
+    let tag_type = u16::from_be_bytes([header[0], header[1]]);
+    let length = u16::from_be_bytes([header[2], header[3]]);
+
+    let value_start = *offset + PPPOE_TAG_HEADER_SIZE;
+    let value = tags
+        .get(value_start..value_start + length as usize)
+        .ok_or(PppoeTagError::Truncated { tag_type, length })?;
+
+    *offset = value_start + length as usize;
+    Ok(PppoeTag { tag_type, value })
+}
@@ -0,0 +1,366 @@
+mod error;
+mod flags;
+mod options;
+
+pub use error::*;
+pub use flags::*;
+pub use options::*;
+
+use crate::buffer::PacketBuffer;
+use crate::checksum;
+use crate::cursor::PacketCursor;
+#[cfg(feature = "fmt")]
+use core::fmt::Debug;
+
+// All ranges are [start..end).
+// This means start is included, end is not included.
+pub static SOURCE_PORT_START: usize = 0;
+pub static SOURCE_PORT_END: usize = 2;
+pub static DESTINATION_PORT_START: usize = 2;
+pub static DESTINATION_PORT_END: usize = 4;
+pub static SEQUENCE_NUMBER_START: usize = 4;
+pub static SEQUENCE_NUMBER_END: usize = 8;
+pub static ACKNOWLEDGMENT_NUMBER_START: usize = 8;
+pub static ACKNOWLEDGMENT_NUMBER_END: usize = 12;
+pub static DATA_OFFSET_FLAGS_START: usize = 12;
+pub static DATA_OFFSET_FLAGS_END: usize = 14;
+pub static WINDOW_START: usize = 14;
+pub static WINDOW_END: usize = 16;
+pub static CHECKSUM_START: usize = 16;
+pub static CHECKSUM_END: usize = 18;
+pub static URGENT_POINTER_START: usize = 18;
+pub static URGENT_POINTER_END: usize = 20;
+pub static OPTIONS_START: usize = 20;
+
+/// Size of a header without options, data offset 5.
+pub static TCP_MIN_HEADER_SIZE: usize = 20;
+/// Size of a header with the maximum data offset of 15.
+pub static TCP_MAX_HEADER_SIZE: usize = 60;
+
+/// TCP header including options, found in the payload of IPv4 and IPv6 packets with
+/// [`IpProtocol::Tcp`](crate::ip::IpProtocol::Tcp).
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(PartialEq, Eq)]
+pub struct TcpHeader<B> {
+    // size of 20B - 60B
+    header: B,
+}
+
+impl<B: PacketBuffer> TcpHeader<B> {
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn new_bpf(buf: B, end: usize) -> Result<(Self, B), TcpBpfCreationError> {
+        let mut cursor = PacketCursor::new(buf, end);
+        let header = Self::from_cursor(&mut cursor)?;
+        Ok((header, cursor.into_inner()))
+    }
+
+    /// Takes the header including options from the front of `cursor` and leaves the cursor at
+    /// the segment data.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_cursor(cursor: &mut PacketCursor<B>) -> Result<Self, TcpBpfCreationError> {
+        // We read at least the first 20 bytes of the segment:
+        // 2 bytes source port
+        // 2 bytes destination port
+        // 4 bytes sequence number
+        // 4 bytes acknowledgment number
+        // 2 bytes data offset and flags
+        // 2 bytes window
+        // 2 bytes checksum
+        // 2 bytes urgent pointer
+        // ------------------
+        // 20 bytes total
+        // The options make up the remaining data offset * 4 - 20 bytes, at most 40 bytes.
+
+        let data_offset = *cursor
+            .peek::<20>()?
+            .get(DATA_OFFSET_FLAGS_START)
+            .ok_or(TcpBpfCreationError::OutOfBoundsBufferAccess)?
+            >> 4;
+        let header_len = data_offset as usize * 4;
+        if header_len < TCP_MIN_HEADER_SIZE {
+            return Err(TcpBpfCreationError::InvalidDataOffset { data_offset });
+        }
+
+        let header = cursor.split_header_len(header_len)?;
+
+        Ok(TcpHeader { header })
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_source_port(&self) -> Result<u16, TcpGetHeaderError> {
+        self.get_u16(SOURCE_PORT_START, SOURCE_PORT_END)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_destination_port(&self) -> Result<u16, TcpGetHeaderError> {
+        self.get_u16(DESTINATION_PORT_START, DESTINATION_PORT_END)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_sequence_number(&self) -> Result<u32, TcpGetHeaderError> {
+        self.get_u32(SEQUENCE_NUMBER_START, SEQUENCE_NUMBER_END)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_acknowledgment_number(&self) -> Result<u32, TcpGetHeaderError> {
+        self.get_u32(ACKNOWLEDGMENT_NUMBER_START, ACKNOWLEDGMENT_NUMBER_END)
+    }
+
+    /// Header length in 32 bit words, see [`TcpHeader::get_header_len`] for bytes.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_data_offset(&self) -> Result<u8, TcpGetHeaderError> {
+        Ok((self.get_u16(DATA_OFFSET_FLAGS_START, DATA_OFFSET_FLAGS_END)? >> 12) as u8)
+    }
+
+    /// Length of the header including options in bytes.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_header_len(&self) -> usize {
+        self.header.len()
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_flags(&self) -> Result<TcpFlags, TcpGetHeaderError> {
+        Ok(TcpFlags::from_bits(
+            self.get_u16(DATA_OFFSET_FLAGS_START, DATA_OFFSET_FLAGS_END)?,
+        ))
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_window(&self) -> Result<u16, TcpGetHeaderError> {
+        self.get_u16(WINDOW_START, WINDOW_END)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_checksum(&self) -> Result<u16, TcpGetHeaderError> {
+        self.get_u16(CHECKSUM_START, CHECKSUM_END)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_urgent_pointer(&self) -> Result<u16, TcpGetHeaderError> {
+        self.get_u16(URGENT_POINTER_START, URGENT_POINTER_END)
+    }
+
+    /// The raw options, empty for data offset 5.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_options(&self) -> Result<&[u8], TcpGetHeaderError> {
+        self.header
+            .get(OPTIONS_START..)
+            .ok_or(TcpGetHeaderError::OutOfBoundsBufferAccess)
+    }
+
+    /// Iterates the options, see [`TcpOptionsIter`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn options(&self) -> Result<TcpOptionsIter<'_>, TcpGetHeaderError> {
+        Ok(TcpOptionsIter::new(self.get_options()?))
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn get_u16(&self, start: usize, end: usize) -> Result<u16, TcpGetHeaderError> {
+        Ok(u16::from_be_bytes(
+            self.header
+                .get(start..end)
+                .ok_or(TcpGetHeaderError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        ))
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn get_u32(&self, start: usize, end: usize) -> Result<u32, TcpGetHeaderError> {
+        Ok(u32::from_be_bytes(
+            self.header
+                .get(start..end)
+                .ok_or(TcpGetHeaderError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        ))
+    }
+}
+
+impl TcpHeader<&mut [u8]> {
+    /// Overwrites the value of the MSS option and updates the checksum incrementally, returns
+    /// the previous value. Used to clamp the MSS of SYN segments.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn set_mss(&mut self, mss: u16) -> Result<u16, TcpSetOptionError> {
+        let mut options = TcpOptionsIter::new(
+            self.header
+                .get(OPTIONS_START..)
+                .ok_or(TcpSetOptionError::OutOfBoundsBufferAccess)?,
+        );
+        let mut mss_start = None;
+        while let Some((offset, option)) = options.next_with_offset() {
+            if let TcpOption::Mss(old) = option? {
+                mss_start = Some((OPTIONS_START + offset + 2, old));
+                break;
+            }
+        }
+        let (mss_start, old) = mss_start.ok_or(TcpSetOptionError::NoSuchOption)?;
+
+        self.header
+            .get_mut(mss_start..mss_start + 2)
+            .ok_or(TcpSetOptionError::OutOfBoundsBufferAccess)?
+            .copy_from_slice(&mss.to_be_bytes());
+
+        // The checksum sums 16 bit words from the start of the header, a value at an odd
+        // offset contributes with swapped bytes.
+        let (old_word, new_word) = if mss_start % 2 == 0 {
+            (old, mss)
+        } else {
+            (old.swap_bytes(), mss.swap_bytes())
+        };
+        let value = u16::from_be_bytes(
+            self.header
+                .get(CHECKSUM_START..CHECKSUM_END)
+                .ok_or(TcpSetOptionError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        );
+        self.header
+            .get_mut(CHECKSUM_START..CHECKSUM_END)
+            .ok_or(TcpSetOptionError::OutOfBoundsBufferAccess)?
+            .copy_from_slice(&checksum::update_u16(value, old_word, new_word).to_be_bytes());
+
+        Ok(old)
+    }
+}
+
+#[cfg(all(test, feature = "fmt"))]
+mod tests {
+    use super::*;
+
+    // Sum of the pseudo-header and the segment data, which set_mss doesn't touch.
+    const PSEUDO_HEADER_SUM: u32 = 0x1234;
+
+    /// A SYN header with 8 bytes of `options` and a valid checksum.
+    fn segment(options: [u8; 8]) -> [u8; 28] {
+        let mut segment = [0u8; 28];
+        segment[0..2].copy_from_slice(&1234u16.to_be_bytes());
+        segment[2..4].copy_from_slice(&80u16.to_be_bytes());
+        segment[DATA_OFFSET_FLAGS_START] = 7 << 4;
+        segment[DATA_OFFSET_FLAGS_START + 1] = 0x02;
+        segment[WINDOW_START..WINDOW_END].copy_from_slice(&64240u16.to_be_bytes());
+        segment[OPTIONS_START..].copy_from_slice(&options);
+        let checksum = full_checksum(&segment);
+        segment[CHECKSUM_START..CHECKSUM_END].copy_from_slice(&checksum.to_be_bytes());
+        segment
+    }
+
+    fn full_checksum(segment: &[u8; 28]) -> u16 {
+        let mut segment = *segment;
+        segment[CHECKSUM_START..CHECKSUM_END].copy_from_slice(&[0, 0]);
+        !checksum::fold(checksum::sum_bytes(PSEUDO_HEADER_SUM, &segment).unwrap())
+    }
+
+    fn set_mss(segment: &mut [u8; 28], mss: u16) -> Result<u16, TcpSetOptionError> {
+        let buf: &mut [u8] = segment;
+        let end = buf.as_ptr() as usize + buf.len();
+        let (mut header, _) = TcpHeader::new_bpf(buf, end).unwrap();
+        header.set_mss(mss)
+    }
+
+    fn options(options: &[u8]) -> TcpOptionsIter<'_> {
+        TcpOptionsIter::new(options)
+    }
+
+    #[test]
+    fn set_mss_at_even_offset_matches_full_checksum() {
+        let mut segment = segment([2, 4, 0x05, 0xB4, 1, 1, 1, 0]);
+
+        assert_eq!(set_mss(&mut segment, 1400), Ok(1460));
+        assert_eq!(&segment[22..24], &1400u16.to_be_bytes());
+        assert_eq!(
+            u16::from_be_bytes([segment[CHECKSUM_START], segment[CHECKSUM_START + 1]]),
+            full_checksum(&segment)
+        );
+    }
+
+    #[test]
+    fn set_mss_at_odd_offset_matches_full_checksum() {
+        let mut segment = segment([1, 2, 4, 0x05, 0xB4, 1, 1, 0]);
+
+        assert_eq!(set_mss(&mut segment, 0x0123), Ok(1460));
+        assert_eq!(&segment[23..25], &0x0123u16.to_be_bytes());
+        assert_eq!(
+            u16::from_be_bytes([segment[CHECKSUM_START], segment[CHECKSUM_START + 1]]),
+            full_checksum(&segment)
+        );
+    }
+
+    #[test]
+    fn set_mss_without_mss_option() {
+        let mut segment = segment([1, 1, 3, 3, 7, 1, 1, 0]);
+        let before = segment;
+
+        assert_eq!(
+            set_mss(&mut segment, 1400),
+            Err(TcpSetOptionError::NoSuchOption)
+        );
+        assert_eq!(segment, before);
+    }
+
+    #[test]
+    fn options_stop_at_end_of_option_list() {
+        let mut iter = options(&[1, 2, 4, 0x05, 0xB4, 0, 2, 4, 0x05, 0xDC]);
+
+        assert_eq!(iter.next(), Some(Ok(TcpOption::Nop)));
+        assert_eq!(iter.next(), Some(Ok(TcpOption::Mss(1460))));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn options_report_offsets() {
+        let mut iter = options(&[1, 1, 3, 3, 7]);
+
+        assert_eq!(iter.next_with_offset(), Some((0, Ok(TcpOption::Nop))));
+        assert_eq!(iter.next_with_offset(), Some((1, Ok(TcpOption::Nop))));
+        assert_eq!(
+            iter.next_with_offset(),
+            Some((2, Ok(TcpOption::WindowScale(7))))
+        );
+        assert_eq!(iter.next_with_offset(), None);
+    }
+
+    #[test]
+    fn truncated_option_ends_iteration() {
+        let mut iter = options(&[1, 8, 10, 0, 0, 0, 1]);
+
+        assert_eq!(iter.next(), Some(Ok(TcpOption::Nop)));
+        assert_eq!(
+            iter.next(),
+            Some(Err(TcpOptionError::Truncated { kind: 8 }))
+        );
+        assert_eq!(iter.next(), None);
+
+        let mut iter = options(&[2]);
+        assert_eq!(
+            iter.next(),
+            Some(Err(TcpOptionError::Truncated { kind: 2 }))
+        );
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn invalid_length_ends_iteration() {
+        let mut iter = options(&[2, 3, 0x05, 1, 1]);
+
+        assert_eq!(
+            iter.next(),
+            Some(Err(TcpOptionError::InvalidLength { kind: 2, length: 3 }))
+        );
+        assert_eq!(iter.next(), None);
+
+        let mut iter = options(&[30, 1, 1, 1]);
+        assert_eq!(
+            iter.next(),
+            Some(Err(TcpOptionError::InvalidLength {
+                kind: 30,
+                length: 1
+            }))
+        );
+    }
+
+    #[test]
+    fn options_are_capped() {
+        let nops = [1u8; 2 * MAX_TCP_OPTIONS];
+
+        assert_eq!(options(&nops).count(), MAX_TCP_OPTIONS);
+    }
+}
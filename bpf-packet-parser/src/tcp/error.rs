@@ -0,0 +1,227 @@
+use crate::cursor::CursorError;
+use core::array::TryFromSliceError;
+#[cfg(feature = "fmt")]
+use core::fmt::{Debug, Display, Formatter};
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum TcpError {
+    BpfCreation(TcpBpfCreationError),
+    GetHeader(TcpGetHeaderError),
+    Option(TcpOptionError),
+    SetOption(TcpSetOptionError),
+}
+
+#[cfg(feature = "fmt")]
+impl Display for TcpError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TcpError::BpfCreation(err) => {
+                write!(f, "{err}")
+            }
+            TcpError::GetHeader(err) => {
+                write!(f, "{err}")
+            }
+            TcpError::Option(err) => {
+                write!(f, "{err}")
+            }
+            TcpError::SetOption(err) => {
+                write!(f, "{err}")
+            }
+        }
+    }
+}
+
+impl From<TcpBpfCreationError> for TcpError {
+    fn from(value: TcpBpfCreationError) -> Self {
+        Self::BpfCreation(value)
+    }
+}
+
+impl From<TcpGetHeaderError> for TcpError {
+    fn from(value: TcpGetHeaderError) -> Self {
+        Self::GetHeader(value)
+    }
+}
+
+impl From<TcpOptionError> for TcpError {
+    fn from(value: TcpOptionError) -> Self {
+        Self::Option(value)
+    }
+}
+
+impl From<TcpSetOptionError> for TcpError {
+    fn from(value: TcpSetOptionError) -> Self {
+        Self::SetOption(value)
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for TcpError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum TcpBpfCreationError {
+    InvalidDataOffset { data_offset: u8 },
+    SegmentTooShort { size: usize, expected: usize },
+    BpfOutOfBounds,
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for TcpBpfCreationError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+impl From<CursorError> for TcpBpfCreationError {
+    fn from(value: CursorError) -> Self {
+        match value {
+            CursorError::TooShort { size, expected } => Self::SegmentTooShort { size, expected },
+            CursorError::BpfOutOfBounds => Self::BpfOutOfBounds,
+            CursorError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            CursorError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for TcpBpfCreationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidDataOffset { data_offset } => {
+                write!(
+                    f,
+                    "TCP header expected data offset of at least 5, was: {data_offset}"
+                )
+            }
+            Self::SegmentTooShort { size, expected } => {
+                write!(
+                    f,
+                    "TCP segment expected to be at least {expected} bytes, was: {size}"
+                )
+            }
+            Self::BpfOutOfBounds => {
+                write!(f, "Bpf out of bounds")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for TcpBpfCreationError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum TcpGetHeaderError {
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for TcpGetHeaderError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for TcpGetHeaderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for TcpGetHeaderError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum TcpOptionError {
+    InvalidLength { kind: u8, length: u8 },
+    Truncated { kind: u8 },
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for TcpOptionError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for TcpOptionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidLength { kind, length } => {
+                write!(f, "TCP option {kind} has invalid length: {length}")
+            }
+            Self::Truncated { kind } => {
+                write!(f, "TCP option {kind} exceeds the options")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for TcpOptionError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum TcpSetOptionError {
+    NoSuchOption,
+    InvalidOption(TcpOptionError),
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for TcpSetOptionError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+impl From<TcpOptionError> for TcpSetOptionError {
+    fn from(value: TcpOptionError) -> Self {
+        Self::InvalidOption(value)
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for TcpSetOptionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NoSuchOption => {
+                write!(f, "TCP header has no such option")
+            }
+            Self::InvalidOption(err) => {
+                write!(f, "{err}")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for TcpSetOptionError {}
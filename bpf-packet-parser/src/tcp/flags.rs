@@ -0,0 +1,76 @@
+#[cfg(feature = "fmt")]
+use core::fmt::Debug;
+use core::ops::{BitAnd, BitOr};
+
+/// The 9 control bits of a TCP header.
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct TcpFlags(u16);
+
+impl TcpFlags {
+    pub const FIN: TcpFlags = TcpFlags(0x001);
+    pub const SYN: TcpFlags = TcpFlags(0x002);
+    pub const RST: TcpFlags = TcpFlags(0x004);
+    pub const PSH: TcpFlags = TcpFlags(0x008);
+    pub const ACK: TcpFlags = TcpFlags(0x010);
+    pub const URG: TcpFlags = TcpFlags(0x020);
+    pub const ECE: TcpFlags = TcpFlags(0x040);
+    pub const CWR: TcpFlags = TcpFlags(0x080);
+    /// Accurate ECN, formerly the ECN nonce bit NS.
+    pub const AE: TcpFlags = TcpFlags(0x100);
+
+    pub const NONE: TcpFlags = TcpFlags(0);
+    pub const ALL: TcpFlags = TcpFlags(0x1FF);
+
+    /// Keeps the lower 9 bits of `bits`, so the data offset and reserved bits can be passed in.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_bits(bits: u16) -> Self {
+        TcpFlags(bits & Self::ALL.0)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn bits(&self) -> u16 {
+        self.0
+    }
+
+    /// All flags in `flags` are set.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn contains(&self, flags: TcpFlags) -> bool {
+        self.0 & flags.0 == flags.0
+    }
+
+    /// Any flag in `flags` is set.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn intersects(&self, flags: TcpFlags) -> bool {
+        self.0 & flags.0 != 0
+    }
+
+    /// SYN without ACK, the first segment of a handshake.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn is_syn(&self) -> bool {
+        self.contains(Self::SYN) && !self.contains(Self::ACK)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn is_syn_ack(&self) -> bool {
+        self.contains(Self::SYN | Self::ACK)
+    }
+}
+
+impl BitOr for TcpFlags {
+    type Output = TcpFlags;
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        TcpFlags(self.0 | rhs.0)
+    }
+}
+
+impl BitAnd for TcpFlags {
+    type Output = TcpFlags;
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        TcpFlags(self.0 & rhs.0)
+    }
+}
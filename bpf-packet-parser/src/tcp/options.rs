@@ -0,0 +1,154 @@
+use crate::tcp::TcpOptionError;
+use crate::tlv::{TlvIter, TlvParser};
+#[cfg(feature = "fmt")]
+use core::fmt::Debug;
+
+pub static OPTION_KIND_END: u8 = 0;
+pub static OPTION_KIND_NOP: u8 = 1;
+pub static OPTION_KIND_MSS: u8 = 2;
+pub static OPTION_KIND_WINDOW_SCALE: u8 = 3;
+pub static OPTION_KIND_SACK_PERMITTED: u8 = 4;
+pub static OPTION_KIND_SACK: u8 = 5;
+pub static OPTION_KIND_TIMESTAMPS: u8 = 8;
+
+pub static SACK_BLOCK_SIZE: usize = 8;
+
+/// Maximum number of options the iterator returns. The options are at most 40 bytes and every
+/// option takes at least 1 byte, so the cap is never hit for valid headers.
+pub const MAX_TCP_OPTIONS: usize = 40;
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum TcpOption<'a> {
+    Nop,
+    /// Maximum segment size
+    Mss(u16),
+    /// Shift count of the window
+    WindowScale(u8),
+    SackPermitted,
+    Sack(SackBlocks<'a>),
+    Timestamps {
+        value: u32,
+        echo_reply: u32,
+    },
+    /// Any other option, `data` excludes kind and length.
+    Unknown {
+        kind: u8,
+        data: &'a [u8],
+    },
+}
+
+/// The 1 to 4 blocks of a SACK option.
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct SackBlocks<'a>(&'a [u8]);
+
+impl<'a> SackBlocks<'a> {
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn len(&self) -> usize {
+        self.0.len() / SACK_BLOCK_SIZE
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Left and right edge of the block at `index`.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_block(&self, index: usize) -> Option<(u32, u32)> {
+        let start = index * SACK_BLOCK_SIZE;
+        let left = u32::from_be_bytes(self.0.get(start..start + 4)?.try_into().ok()?);
+        let right = u32::from_be_bytes(self.0.get(start + 4..start + 8)?.try_into().ok()?);
+        Some((left, right))
+    }
+}
+
+/// Iterator over the options of a [`TcpHeader`](crate::tcp::TcpHeader), stops at the end of
+/// option list option.
+pub type TcpOptionsIter<'a> = TlvIter<'a, TcpOptionParser>;
+
+/// Parses the options of a [`TcpOptionsIter`].
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Default)]
+pub struct TcpOptionParser;
+
+impl<'a> TlvParser<'a> for TcpOptionParser {
+    type Item = TcpOption<'a>;
+    type Error = TcpOptionError;
+
+    const MAX: usize = MAX_TCP_OPTIONS;
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn parse(
+        &mut self,
+        options: &'a [u8],
+        offset: &mut usize,
+    ) -> Option<Result<TcpOption<'a>, TcpOptionError>> {
+        let kind = *options.get(*offset)?;
+        if kind == OPTION_KIND_END {
+            return None;
+        }
+        if kind == OPTION_KIND_NOP {
+            *offset += 1;
+            return Some(Ok(TcpOption::Nop));
+        }
+        Some(parse_option(options, kind, offset))
+    }
+}
+
+#[cfg_attr(feature = "inline", inline(always))]
+fn parse_option<'a>(
+    options: &'a [u8],
+    kind: u8,
+    offset: &mut usize,
+) -> Result<TcpOption<'a>, TcpOptionError> {
+    let length = *options
+        .get(*offset + 1)
+        .ok_or(TcpOptionError::Truncated { kind })?;
+    // The length covers kind and length itself.
+    if length < 2 {
+        return Err(TcpOptionError::InvalidLength { kind, length });
+    }
+    let data = options
+        .get(*offset + 2..*offset + length as usize)
+        .ok_or(TcpOptionError::Truncated { kind })?;
+
+    let option = if kind == OPTION_KIND_MSS && length == 4 {
+        TcpOption::Mss(u16::from_be_bytes(data.try_into()?))
+    } else if kind == OPTION_KIND_WINDOW_SCALE && length == 3 {
+        TcpOption::WindowScale(*data.first().ok_or(TcpOptionError::Truncated { kind })?)
+    } else if kind == OPTION_KIND_SACK_PERMITTED && length == 2 {
+        TcpOption::SackPermitted
+    } else if kind == OPTION_KIND_SACK
+        && data.len() >= SACK_BLOCK_SIZE
+        && data.len() % SACK_BLOCK_SIZE == 0
+    {
+        TcpOption::Sack(SackBlocks(data))
+    } else if kind == OPTION_KIND_TIMESTAMPS && length == 10 {
+        TcpOption::Timestamps {
+            value: u32::from_be_bytes(
+                data.get(0..4)
+                    .ok_or(TcpOptionError::Truncated { kind })?
+                    .try_into()?,
+            ),
+            echo_reply: u32::from_be_bytes(
+                data.get(4..8)
+                    .ok_or(TcpOptionError::Truncated { kind })?
+                    .try_into()?,
+            ),
+        }
+    } else if kind == OPTION_KIND_MSS
+        || kind == OPTION_KIND_WINDOW_SCALE
+        || kind == OPTION_KIND_SACK_PERMITTED
+        || kind == OPTION_KIND_SACK
+        || kind == OPTION_KIND_TIMESTAMPS
+    {
+        return Err(TcpOptionError::InvalidLength { kind, length });
+    } else {
+        TcpOption::Unknown { kind, data }
+    };
+
+    *offset += length as usize;
+    Ok(option)
+}
@@ -0,0 +1,84 @@
+#[cfg(feature = "fmt")]
+use core::fmt::Debug;
+
+/// Parses the elements of a type-length-value list, e.g. TCP options or PPPoE tags, for
+/// [`TlvIter`].
+pub trait TlvParser<'a> {
+    type Item;
+    type Error;
+
+    /// Maximum number of elements the iterator returns.
+    const MAX: usize;
+
+    /// Parses the element at `offset` of `data` and moves `offset` behind it. `offset` is
+    /// always inside of `data`.
+    ///
+    /// Returns `None` if the element marks the end of the list.
+    fn parse(
+        &mut self,
+        data: &'a [u8],
+        offset: &mut usize,
+    ) -> Option<Result<Self::Item, Self::Error>>;
+}
+
+/// Result of parsing an element with `P`.
+pub type TlvResult<'a, P> = Result<<P as TlvParser<'a>>::Item, <P as TlvParser<'a>>::Error>;
+
+/// Iterator over a type-length-value list whose elements are parsed by `P`.
+///
+/// Stops at the end of `data`, at an end marker recognized by `P` or after [`TlvParser::MAX`]
+/// elements, see [bounded loops](crate#bounded-loops). After an error no further elements are
+/// returned, the bytes behind a malformed element can't be trusted.
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+pub struct TlvIter<'a, P> {
+    data: &'a [u8],
+    offset: usize,
+    count: usize,
+    parser: P,
+}
+
+impl<'a, P: TlvParser<'a> + Default> TlvIter<'a, P> {
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn new(data: &'a [u8]) -> Self {
+        Self::with_parser(data, P::default())
+    }
+}
+
+impl<'a, P: TlvParser<'a>> TlvIter<'a, P> {
+    /// Like [`TlvIter::new`] for parsers which carry state between elements.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn with_parser(data: &'a [u8], parser: P) -> Self {
+        TlvIter {
+            data,
+            offset: 0,
+            count: 0,
+            parser,
+        }
+    }
+
+    /// Like [`Iterator::next`], additionally returns the offset of the element in `data`.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn next_with_offset(&mut self) -> Option<(usize, TlvResult<'a, P>)> {
+        if self.count >= P::MAX || self.offset >= self.data.len() {
+            return None;
+        }
+        self.count += 1;
+
+        let offset = self.offset;
+        let item = self.parser.parse(self.data, &mut self.offset);
+        if !matches!(item, Some(Ok(_))) {
+            self.offset = self.data.len();
+        }
+        item.map(|item| (offset, item))
+    }
+}
+
+impl<'a, P: TlvParser<'a>> Iterator for TlvIter<'a, P> {
+    type Item = TlvResult<'a, P>;
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_with_offset().map(|(_, item)| item)
+    }
+}
@@ -0,0 +1,235 @@
+mod error;
+
+pub use error::*;
+
+use crate::buffer::PacketBuffer;
+use crate::checksum;
+use crate::cursor::PacketCursor;
+use crate::ip::IpProtocol;
+#[cfg(feature = "fmt")]
+use core::fmt::Debug;
+
+// All ranges are [start..end).
+// This means start is included, end is not included.
+pub static SOURCE_PORT_START: usize = 0;
+pub static SOURCE_PORT_END: usize = 2;
+pub static DESTINATION_PORT_START: usize = 2;
+pub static DESTINATION_PORT_END: usize = 4;
+pub static LENGTH_START: usize = 4;
+pub static LENGTH_END: usize = 6;
+pub static CHECKSUM_START: usize = 6;
+pub static CHECKSUM_END: usize = 8;
+
+pub static UDP_HEADER_SIZE: usize = 8;
+
+/// UDP header, found in the payload of IPv4 and IPv6 packets with
+/// [`IpProtocol::Udp`](crate::ip::IpProtocol::Udp).
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(PartialEq, Eq)]
+pub struct UdpHeader<B> {
+    // size of 8B
+    header: B,
+}
+
+impl<B: PacketBuffer> UdpHeader<B> {
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn new_bpf(buf: B, end: usize) -> Result<(Self, B), UdpBpfCreationError> {
+        let mut cursor = PacketCursor::new(buf, end);
+        let header = Self::from_cursor(&mut cursor)?;
+        Ok((header, cursor.into_inner()))
+    }
+
+    /// Takes the header from the front of `cursor` and leaves the cursor at the payload.
+    ///
+    /// The length field has to cover at least the header and at most the header and the rest
+    /// of the cursor. The payload is not cut to the length, it may still contain the Ethernet
+    /// padding of short packets, see [`UdpHeader::get_payload_len`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_cursor(cursor: &mut PacketCursor<B>) -> Result<Self, UdpBpfCreationError> {
+        // We read the first 8 bytes of the datagram:
+        // 2 bytes source port
+        // 2 bytes destination port
+        // 2 bytes length
+        // 2 bytes checksum
+        // ------------------
+        // 8 bytes total
+
+        let length = u16::from_be_bytes(
+            cursor
+                .peek::<8>()?
+                .get(LENGTH_START..LENGTH_END)
+                .ok_or(UdpBpfCreationError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        );
+        if (length as usize) < UDP_HEADER_SIZE {
+            return Err(UdpBpfCreationError::InvalidLength { length });
+        }
+        if length as usize > cursor.len() {
+            return Err(UdpBpfCreationError::LengthExceedsPayload {
+                length,
+                size: cursor.len(),
+            });
+        }
+
+        let header = cursor.split_header::<8>()?;
+
+        Ok(UdpHeader { header })
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_source_port(&self) -> Result<u16, UdpGetHeaderError> {
+        self.get_u16(SOURCE_PORT_START, SOURCE_PORT_END)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_destination_port(&self) -> Result<u16, UdpGetHeaderError> {
+        self.get_u16(DESTINATION_PORT_START, DESTINATION_PORT_END)
+    }
+
+    /// Length of header and payload in bytes.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_length(&self) -> Result<u16, UdpGetHeaderError> {
+        self.get_u16(LENGTH_START, LENGTH_END)
+    }
+
+    /// Length of the payload in bytes according to the length field.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_payload_len(&self) -> Result<usize, UdpGetHeaderError> {
+        Ok(self.get_length()? as usize - UDP_HEADER_SIZE)
+    }
+
+    /// 0 if the sender didn't compute a checksum, only allowed for IPv4.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_checksum(&self) -> Result<u16, UdpGetHeaderError> {
+        self.get_u16(CHECKSUM_START, CHECKSUM_END)
+    }
+
+    /// Verifies the checksum over the IPv4 pseudo-header, this header and `payload`, which has
+    /// to start with the payload of this header. A checksum of 0 means no checksum and is
+    /// reported as valid.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn verify_checksum_ipv4(
+        &self,
+        source: &[u8; 4],
+        destination: &[u8; 4],
+        payload: &[u8],
+    ) -> Result<bool, UdpChecksumError> {
+        if self.get_checksum()? == 0 {
+            return Ok(true);
+        }
+
+        let length = self.get_length()?;
+        let sum = checksum::pseudo_header_ipv4(source, destination, IpProtocol::Udp as u8, length);
+        self.verify_checksum(sum, payload)
+    }
+
+    /// Verifies the checksum over the IPv6 pseudo-header, this header and `payload`, which has
+    /// to start with the payload of this header. A checksum of 0 is reported as invalid.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn verify_checksum_ipv6(
+        &self,
+        source: &[u8; 16],
+        destination: &[u8; 16],
+        payload: &[u8],
+    ) -> Result<bool, UdpChecksumError> {
+        if self.get_checksum()? == 0 {
+            return Ok(false);
+        }
+
+        let length = self.get_length()?;
+        let sum =
+            checksum::pseudo_header_ipv6(source, destination, IpProtocol::Udp as u8, length as u32);
+        self.verify_checksum(sum, payload)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn verify_checksum(&self, sum: u32, payload: &[u8]) -> Result<bool, UdpChecksumError> {
+        let payload_len = self.get_payload_len()?;
+        let payload = payload
+            .get(..payload_len)
+            .ok_or(UdpChecksumError::PayloadTooShort {
+                size: payload.len(),
+                expected: payload_len,
+            })?;
+
+        let sum = checksum::sum_chunk(sum, &self.header);
+        let sum = checksum::sum_bytes(sum, payload).ok_or(UdpChecksumError::PayloadTooLong {
+            size: payload.len(),
+        })?;
+        Ok(checksum::fold(sum) == 0xFFFF)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn get_u16(&self, start: usize, end: usize) -> Result<u16, UdpGetHeaderError> {
+        Ok(u16::from_be_bytes(
+            self.header
+                .get(start..end)
+                .ok_or(UdpGetHeaderError::OutOfBoundsBufferAccess)?
+                .try_into()?,
+        ))
+    }
+}
+
+#[cfg(all(test, feature = "fmt"))]
+mod tests {
+    use super::*;
+
+    const SOURCE_IPV4: [u8; 4] = [192, 168, 1, 10];
+    const DESTINATION_IPV4: [u8; 4] = [192, 168, 1, 1];
+    const SOURCE_IPV6: [u8; 16] = [0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+    const DESTINATION_IPV6: [u8; 16] = [0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2];
+
+    // Port 54321 to 53 with the odd length payload "hello".
+    const DATAGRAM_IPV4: [u8; 13] = [
+        0xd4, 0x31, 0x00, 0x35, 0x00, 0x0d, 0x64, 0x3f, b'h', b'e', b'l', b'l', b'o',
+    ];
+    const DATAGRAM_IPV6: [u8; 13] = [
+        0xd4, 0x31, 0x00, 0x35, 0x00, 0x0d, 0x8c, 0x26, b'h', b'e', b'l', b'l', b'o',
+    ];
+
+    fn verify_ipv4(datagram: &[u8]) -> Result<bool, UdpChecksumError> {
+        let end = datagram.as_ptr() as usize + datagram.len();
+        let (header, payload) = UdpHeader::new_bpf(datagram, end).unwrap();
+        header.verify_checksum_ipv4(&SOURCE_IPV4, &DESTINATION_IPV4, payload)
+    }
+
+    fn verify_ipv6(datagram: &[u8]) -> Result<bool, UdpChecksumError> {
+        let end = datagram.as_ptr() as usize + datagram.len();
+        let (header, payload) = UdpHeader::new_bpf(datagram, end).unwrap();
+        header.verify_checksum_ipv6(&SOURCE_IPV6, &DESTINATION_IPV6, payload)
+    }
+
+    #[test]
+    fn verify_checksum_ipv4_datagram() {
+        assert_eq!(verify_ipv4(&DATAGRAM_IPV4), Ok(true));
+
+        let mut datagram = DATAGRAM_IPV4;
+        datagram[12] ^= 0x01;
+        assert_eq!(verify_ipv4(&datagram), Ok(false));
+    }
+
+    #[test]
+    fn verify_checksum_ipv6_datagram() {
+        assert_eq!(verify_ipv6(&DATAGRAM_IPV6), Ok(true));
+
+        let mut datagram = DATAGRAM_IPV6;
+        datagram[12] ^= 0x01;
+        assert_eq!(verify_ipv6(&datagram), Ok(false));
+    }
+
+    #[test]
+    fn verify_checksum_ignores_padding() {
+        let mut datagram = [0u8; 16];
+        datagram[..13].copy_from_slice(&DATAGRAM_IPV4);
+        datagram[13..].copy_from_slice(&[0xAA, 0xBB, 0xCC]);
+        assert_eq!(verify_ipv4(&datagram), Ok(true));
+    }
+
+    #[test]
+    fn verify_checksum_zero() {
+        let mut datagram = DATAGRAM_IPV4;
+        datagram[CHECKSUM_START..CHECKSUM_END].copy_from_slice(&[0, 0]);
+        assert_eq!(verify_ipv4(&datagram), Ok(true));
+        assert_eq!(verify_ipv6(&datagram), Ok(false));
+    }
+}
@@ -0,0 +1,187 @@
+use crate::cursor::CursorError;
+use core::array::TryFromSliceError;
+#[cfg(feature = "fmt")]
+use core::fmt::{Debug, Display, Formatter};
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum UdpError {
+    BpfCreation(UdpBpfCreationError),
+    GetHeader(UdpGetHeaderError),
+    Checksum(UdpChecksumError),
+}
+
+#[cfg(feature = "fmt")]
+impl Display for UdpError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            UdpError::BpfCreation(err) => {
+                write!(f, "{err}")
+            }
+            UdpError::GetHeader(err) => {
+                write!(f, "{err}")
+            }
+            UdpError::Checksum(err) => {
+                write!(f, "{err}")
+            }
+        }
+    }
+}
+
+impl From<UdpBpfCreationError> for UdpError {
+    fn from(value: UdpBpfCreationError) -> Self {
+        Self::BpfCreation(value)
+    }
+}
+
+impl From<UdpGetHeaderError> for UdpError {
+    fn from(value: UdpGetHeaderError) -> Self {
+        Self::GetHeader(value)
+    }
+}
+
+impl From<UdpChecksumError> for UdpError {
+    fn from(value: UdpChecksumError) -> Self {
+        Self::Checksum(value)
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for UdpError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum UdpBpfCreationError {
+    InvalidLength { length: u16 },
+    LengthExceedsPayload { length: u16, size: usize },
+    DatagramTooShort { size: usize, expected: usize },
+    BpfOutOfBounds,
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for UdpBpfCreationError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+impl From<CursorError> for UdpBpfCreationError {
+    fn from(value: CursorError) -> Self {
+        match value {
+            CursorError::TooShort { size, expected } => Self::DatagramTooShort { size, expected },
+            CursorError::BpfOutOfBounds => Self::BpfOutOfBounds,
+            CursorError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            CursorError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for UdpBpfCreationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidLength { length } => {
+                write!(f, "UDP length expected to be at least 8, was: {length}")
+            }
+            Self::LengthExceedsPayload { length, size } => {
+                write!(
+                    f,
+                    "UDP length {length} exceeds the {size} bytes left in the packet"
+                )
+            }
+            Self::DatagramTooShort { size, expected } => {
+                write!(
+                    f,
+                    "UDP datagram expected to be at least {expected} bytes, was: {size}"
+                )
+            }
+            Self::BpfOutOfBounds => {
+                write!(f, "Bpf out of bounds")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for UdpBpfCreationError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum UdpGetHeaderError {
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for UdpGetHeaderError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for UdpGetHeaderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for UdpGetHeaderError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum UdpChecksumError {
+    PayloadTooShort { size: usize, expected: usize },
+    PayloadTooLong { size: usize },
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<UdpGetHeaderError> for UdpChecksumError {
+    fn from(value: UdpGetHeaderError) -> Self {
+        match value {
+            UdpGetHeaderError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            UdpGetHeaderError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for UdpChecksumError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::PayloadTooShort { size, expected } => {
+                write!(
+                    f,
+                    "UDP payload expected to be at least {expected} bytes, was: {size}"
+                )
+            }
+            Self::PayloadTooLong { size } => {
+                write!(f, "UDP payload too long to checksum, was: {size}")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for UdpChecksumError {}
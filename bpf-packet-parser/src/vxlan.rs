@@ -0,0 +1,444 @@
+mod error;
+
+pub use error::*;
+
+use crate::buffer::PacketBuffer;
+use crate::checksum::{fold, pseudo_header_ipv6, sum_bytes, sum_chunk};
+use crate::cursor::PacketCursor;
+use crate::ethernet::{EtherFrame, EtherType};
+use crate::ip::IpProtocol;
+use crate::ipv4::IPV4_MIN_HEADER_SIZE;
+use crate::ipv6::IPV6_HEADER_SIZE;
+use crate::udp::UdpHeader;
+#[cfg(feature = "fmt")]
+use core::fmt::Debug;
+
+// All ranges are [start..end).
+// This means start is included, end is not included.
+pub static FLAGS: usize = 0;
+pub static VNI_START: usize = 4;
+pub static VNI_END: usize = 7;
+
+pub static VXLAN_HEADER_SIZE: usize = 8;
+/// IANA assigned UDP destination port.
+pub static VXLAN_UDP_PORT: u16 = 4789;
+/// The I flag, set if the VNI is valid. RFC 7348 requires it to be set.
+pub static FLAG_VNI_VALID: u8 = 0x08;
+
+/// Headroom [`push_vxlan_ipv4`] needs in front of the inner frame:
+/// 14 bytes Ethernet, 20 bytes IPv4, 8 bytes UDP and 8 bytes VXLAN.
+pub static VXLAN_IPV4_HEADROOM: usize = 50;
+/// Headroom [`push_vxlan_ipv6`] needs in front of the inner frame:
+/// 14 bytes Ethernet, 40 bytes IPv6, 8 bytes UDP and 8 bytes VXLAN.
+pub static VXLAN_IPV6_HEADROOM: usize = 70;
+static OUTER_ETHERNET_SIZE: usize = 14;
+
+/// Outer headers written by [`push_vxlan_ipv4`].
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct VxlanIpv4Encap {
+    pub destination_mac: [u8; 6],
+    pub source_mac: [u8; 6],
+    pub source: [u8; 4],
+    pub destination: [u8; 4],
+    pub ttl: u8,
+    /// Usually a hash of the inner flow, so the underlay can balance the tunnel traffic.
+    pub source_port: u16,
+    /// Truncated to 24 bits.
+    pub vni: u32,
+}
+
+/// Outer headers written by [`push_vxlan_ipv6`].
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct VxlanIpv6Encap {
+    pub destination_mac: [u8; 6],
+    pub source_mac: [u8; 6],
+    pub source: [u8; 16],
+    pub destination: [u8; 16],
+    pub hop_limit: u8,
+    /// Usually a hash of the inner flow, so the underlay can balance the tunnel traffic.
+    pub source_port: u16,
+    /// Truncated to 24 bits.
+    pub vni: u32,
+}
+
+/// VXLAN header, found in the payload of UDP datagrams to [`VXLAN_UDP_PORT`].
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(PartialEq, Eq)]
+pub struct VxlanHeader<B> {
+    // size of 8B
+    header: B,
+}
+
+impl<B: PacketBuffer> VxlanHeader<B> {
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn new_bpf(buf: B, end: usize) -> Result<(Self, B), VxlanBpfCreationError> {
+        let mut cursor = PacketCursor::new(buf, end);
+        let header = Self::from_cursor(&mut cursor)?;
+        Ok((header, cursor.into_inner()))
+    }
+
+    /// Takes the header from the front of `cursor` and leaves the cursor at the inner frame.
+    /// The UDP port is not checked, see [`VxlanPacket::from_cursor`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_cursor(cursor: &mut PacketCursor<B>) -> Result<Self, VxlanBpfCreationError> {
+        // We read the first 8 bytes of the header:
+        // 1 byte flags
+        // 3 bytes reserved
+        // 3 bytes VNI
+        // 1 byte reserved
+        // ------------------
+        // 8 bytes total
+
+        let flags = *cursor
+            .peek::<8>()?
+            .get(FLAGS)
+            .ok_or(VxlanBpfCreationError::OutOfBoundsBufferAccess)?;
+        if flags & FLAG_VNI_VALID == 0 {
+            return Err(VxlanBpfCreationError::InvalidFlags { flags });
+        }
+
+        let header = cursor.split_header::<8>()?;
+
+        Ok(VxlanHeader { header })
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_flags(&self) -> Result<u8, VxlanGetHeaderError> {
+        self.header
+            .get(FLAGS)
+            .copied()
+            .ok_or(VxlanGetHeaderError::OutOfBoundsBufferAccess)
+    }
+
+    /// VXLAN network identifier, 24 bits.
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_vni(&self) -> Result<u32, VxlanGetHeaderError> {
+        let vni: &[u8; 3] = self
+            .header
+            .get(VNI_START..VNI_END)
+            .ok_or(VxlanGetHeaderError::OutOfBoundsBufferAccess)?
+            .try_into()?;
+        Ok(u32::from_be_bytes([0, vni[0], vni[1], vni[2]]))
+    }
+}
+
+/// The outer UDP and VXLAN header together with the inner frame of a VXLAN packet.
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(PartialEq, Eq)]
+pub struct VxlanPacket<B> {
+    udp: UdpHeader<B>,
+    vxlan: VxlanHeader<B>,
+    inner: EtherFrame<B>,
+}
+
+impl<B: PacketBuffer> VxlanPacket<B> {
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn new_bpf(buf: B, end: usize) -> Result<(Self, B), VxlanDecapError> {
+        let mut cursor = PacketCursor::new(buf, end);
+        let packet = Self::from_cursor(&mut cursor)?;
+        Ok((packet, cursor.into_inner()))
+    }
+
+    /// Takes the UDP header, the VXLAN header and the inner frame header from the front of
+    /// `cursor` and leaves the cursor at the payload of the inner frame.
+    ///
+    /// The UDP destination port has to be [`VXLAN_UDP_PORT`]. The inner frame is parsed with
+    /// [`EtherFrame::from_cursor`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_cursor(cursor: &mut PacketCursor<B>) -> Result<Self, VxlanDecapError> {
+        Self::from_cursor_impl(cursor, false)
+    }
+
+    /// Like [`VxlanPacket::from_cursor`], but the inner frame is parsed with
+    /// [`EtherFrame::from_cursor_lenient`].
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn from_cursor_lenient(cursor: &mut PacketCursor<B>) -> Result<Self, VxlanDecapError> {
+        Self::from_cursor_impl(cursor, true)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn from_cursor_impl(
+        cursor: &mut PacketCursor<B>,
+        lenient: bool,
+    ) -> Result<Self, VxlanDecapError> {
+        let udp = UdpHeader::from_cursor(cursor)?;
+        let port = udp.get_destination_port()?;
+        if port != VXLAN_UDP_PORT {
+            return Err(VxlanDecapError::WrongPort { port });
+        }
+
+        let vxlan = VxlanHeader::from_cursor(cursor)?;
+        let inner = if lenient {
+            EtherFrame::from_cursor_lenient(cursor)?
+        } else {
+            EtherFrame::from_cursor(cursor)?
+        };
+
+        Ok(VxlanPacket { udp, vxlan, inner })
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_udp(&self) -> &UdpHeader<B> {
+        &self.udp
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_vxlan(&self) -> &VxlanHeader<B> {
+        &self.vxlan
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_inner(&self) -> &EtherFrame<B> {
+        &self.inner
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn get_inner_mut(&mut self) -> &mut EtherFrame<B> {
+        &mut self.inner
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    pub fn into_parts(self) -> (UdpHeader<B>, VxlanHeader<B>, EtherFrame<B>) {
+        (self.udp, self.vxlan, self.inner)
+    }
+}
+
+/// Wraps a frame into Ethernet, IPv4, UDP and VXLAN headers.
+///
+/// `buf` has to start with [`VXLAN_IPV4_HEADROOM`] bytes of headroom, e.g. made by calling
+/// `bpf_xdp_adjust_head` with `-50`, directly followed by the inner frame. The IPv4 header
+/// gets the don't fragment flag and its checksum, the UDP checksum is 0 as recommended by
+/// RFC 7348.
+///
+/// Returns the outer frame and its payload, starting at the IPv4 header.
+#[cfg_attr(feature = "inline", inline(always))]
+pub fn push_vxlan_ipv4<'a>(
+    buf: &'a mut [u8],
+    end: usize,
+    encap: &VxlanIpv4Encap,
+) -> Result<(EtherFrame<&'a mut [u8]>, &'a mut [u8]), VxlanEncapError> {
+    // We write the first 50 bytes of the packet:
+    // 14 bytes Ethernet
+    // 20 bytes IPv4
+    // 8 bytes UDP
+    // 8 bytes VXLAN
+    // ------------------
+    // 50 bytes total
+
+    let cursor = PacketCursor::new(buf, end);
+    cursor.check_len(VXLAN_IPV4_HEADROOM)?;
+    let udp_len = cursor.len() - OUTER_ETHERNET_SIZE - IPV4_MIN_HEADER_SIZE;
+    let total_len = IPV4_MIN_HEADER_SIZE + udp_len;
+    if total_len > u16::MAX as usize {
+        return Err(VxlanEncapError::PacketTooLong { size: total_len });
+    }
+    let buf = cursor.into_inner();
+
+    let headers = buf
+        .get_mut(..VXLAN_IPV4_HEADROOM)
+        .ok_or(VxlanEncapError::OutOfBoundsBufferAccess)?;
+    write_ethernet(
+        headers,
+        &encap.destination_mac,
+        &encap.source_mac,
+        EtherType::IpV4,
+    )?;
+
+    let [total_len_0, total_len_1] = (total_len as u16).to_be_bytes();
+    let [src_0, src_1, src_2, src_3] = encap.source;
+    let [dst_0, dst_1, dst_2, dst_3] = encap.destination;
+    let ipv4_start = OUTER_ETHERNET_SIZE;
+    let ipv4_end = ipv4_start + IPV4_MIN_HEADER_SIZE;
+    write(
+        headers,
+        ipv4_start,
+        &[
+            0x45,
+            0,
+            total_len_0,
+            total_len_1,
+            0,
+            0,
+            0x40,
+            0,
+            encap.ttl,
+            IpProtocol::Udp as u8,
+            0,
+            0,
+            src_0,
+            src_1,
+            src_2,
+            src_3,
+            dst_0,
+            dst_1,
+            dst_2,
+            dst_3,
+        ],
+    )?;
+    let checksum = !fold(sum_chunk(
+        0,
+        headers
+            .get(ipv4_start..ipv4_end)
+            .ok_or(VxlanEncapError::OutOfBoundsBufferAccess)?,
+    ));
+    write(headers, ipv4_start + 10, &checksum.to_be_bytes())?;
+
+    write_udp_vxlan(headers, ipv4_end, udp_len, encap.source_port, encap.vni)?;
+
+    finish_encap(buf, end)
+}
+
+/// Like [`push_vxlan_ipv4`] with an IPv6 underlay. `buf` has to start with
+/// [`VXLAN_IPV6_HEADROOM`] bytes of headroom.
+///
+/// The UDP checksum is calculated over the whole inner frame, which fails for frames longer
+/// than [`sum_bytes`] can sum.
+#[cfg_attr(feature = "inline", inline(always))]
+pub fn push_vxlan_ipv6<'a>(
+    buf: &'a mut [u8],
+    end: usize,
+    encap: &VxlanIpv6Encap,
+) -> Result<(EtherFrame<&'a mut [u8]>, &'a mut [u8]), VxlanEncapError> {
+    // We write the first 70 bytes of the packet:
+    // 14 bytes Ethernet
+    // 40 bytes IPv6
+    // 8 bytes UDP
+    // 8 bytes VXLAN
+    // ------------------
+    // 70 bytes total
+
+    let cursor = PacketCursor::new(buf, end);
+    cursor.check_len(VXLAN_IPV6_HEADROOM)?;
+    let udp_len = cursor.len() - OUTER_ETHERNET_SIZE - IPV6_HEADER_SIZE;
+    if udp_len > u16::MAX as usize {
+        return Err(VxlanEncapError::PacketTooLong {
+            size: IPV6_HEADER_SIZE + udp_len,
+        });
+    }
+    let buf = cursor.into_inner();
+
+    let headers = buf
+        .get_mut(..VXLAN_IPV6_HEADROOM)
+        .ok_or(VxlanEncapError::OutOfBoundsBufferAccess)?;
+    write_ethernet(
+        headers,
+        &encap.destination_mac,
+        &encap.source_mac,
+        EtherType::IpV6,
+    )?;
+
+    let ipv6_start = OUTER_ETHERNET_SIZE;
+    let ipv6_end = ipv6_start + IPV6_HEADER_SIZE;
+    let [udp_len_0, udp_len_1] = (udp_len as u16).to_be_bytes();
+    write(
+        headers,
+        ipv6_start,
+        &[
+            0x60,
+            0,
+            0,
+            0,
+            udp_len_0,
+            udp_len_1,
+            IpProtocol::Udp as u8,
+            encap.hop_limit,
+        ],
+    )?;
+    write(headers, ipv6_start + 8, &encap.source)?;
+    write(headers, ipv6_start + 24, &encap.destination)?;
+
+    write_udp_vxlan(headers, ipv6_end, udp_len, encap.source_port, encap.vni)?;
+
+    let pseudo_header = pseudo_header_ipv6(
+        &encap.source,
+        &encap.destination,
+        IpProtocol::Udp as u8,
+        udp_len as u32,
+    );
+    let sum = sum_bytes(
+        pseudo_header,
+        buf.get(ipv6_end..)
+            .ok_or(VxlanEncapError::OutOfBoundsBufferAccess)?,
+    )
+    .ok_or(VxlanEncapError::PacketTooLong {
+        size: IPV6_HEADER_SIZE + udp_len,
+    })?;
+    let checksum = match !fold(sum) {
+        // A zero checksum means no checksum, it is sent as all ones instead.
+        0 => 0xFFFF,
+        checksum => checksum,
+    };
+    write(buf, ipv6_end + 6, &checksum.to_be_bytes())?;
+
+    finish_encap(buf, end)
+}
+
+#[cfg_attr(feature = "inline", inline(always))]
+fn write_ethernet(
+    headers: &mut [u8],
+    destination_mac: &[u8; 6],
+    source_mac: &[u8; 6],
+    ether_type: EtherType,
+) -> Result<(), VxlanEncapError> {
+    write(headers, 0, destination_mac)?;
+    write(headers, 6, source_mac)?;
+    write(headers, 12, &(ether_type as u16).to_be_bytes())
+}
+
+/// Writes the UDP header with checksum 0 and the VXLAN header at `start`.
+#[cfg_attr(feature = "inline", inline(always))]
+fn write_udp_vxlan(
+    headers: &mut [u8],
+    start: usize,
+    udp_len: usize,
+    source_port: u16,
+    vni: u32,
+) -> Result<(), VxlanEncapError> {
+    let [src_port_0, src_port_1] = source_port.to_be_bytes();
+    let [dst_port_0, dst_port_1] = VXLAN_UDP_PORT.to_be_bytes();
+    let [udp_len_0, udp_len_1] = (udp_len as u16).to_be_bytes();
+    let [_, vni_0, vni_1, vni_2] = vni.to_be_bytes();
+    write(
+        headers,
+        start,
+        &[
+            src_port_0,
+            src_port_1,
+            dst_port_0,
+            dst_port_1,
+            udp_len_0,
+            udp_len_1,
+            0,
+            0,
+            FLAG_VNI_VALID,
+            0,
+            0,
+            0,
+            vni_0,
+            vni_1,
+            vni_2,
+            0,
+        ],
+    )
+}
+
+#[cfg_attr(feature = "inline", inline(always))]
+fn write(headers: &mut [u8], start: usize, value: &[u8]) -> Result<(), VxlanEncapError> {
+    headers
+        .get_mut(start..start + value.len())
+        .ok_or(VxlanEncapError::OutOfBoundsBufferAccess)?
+        .copy_from_slice(value);
+    Ok(())
+}
+
+#[cfg_attr(feature = "inline", inline(always))]
+fn finish_encap(
+    buf: &mut [u8],
+    end: usize,
+) -> Result<(EtherFrame<&mut [u8]>, &mut [u8]), VxlanEncapError> {
+    let mut cursor = PacketCursor::new(buf, end);
+    let frame = EtherFrame::from_cursor(&mut cursor)?;
+    Ok((frame, cursor.into_inner()))
+}
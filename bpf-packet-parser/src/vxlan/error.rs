@@ -0,0 +1,284 @@
+use crate::cursor::CursorError;
+use crate::ethernet::EthernetBpfAutoVlanCreationError;
+use crate::udp::{UdpBpfCreationError, UdpGetHeaderError};
+use core::array::TryFromSliceError;
+#[cfg(feature = "fmt")]
+use core::fmt::{Debug, Display, Formatter};
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum VxlanError {
+    BpfCreation(VxlanBpfCreationError),
+    GetHeader(VxlanGetHeaderError),
+    Decap(VxlanDecapError),
+    Encap(VxlanEncapError),
+}
+
+#[cfg(feature = "fmt")]
+impl Display for VxlanError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            VxlanError::BpfCreation(err) => {
+                write!(f, "{err}")
+            }
+            VxlanError::GetHeader(err) => {
+                write!(f, "{err}")
+            }
+            VxlanError::Decap(err) => {
+                write!(f, "{err}")
+            }
+            VxlanError::Encap(err) => {
+                write!(f, "{err}")
+            }
+        }
+    }
+}
+
+impl From<VxlanBpfCreationError> for VxlanError {
+    fn from(value: VxlanBpfCreationError) -> Self {
+        Self::BpfCreation(value)
+    }
+}
+
+impl From<VxlanGetHeaderError> for VxlanError {
+    fn from(value: VxlanGetHeaderError) -> Self {
+        Self::GetHeader(value)
+    }
+}
+
+impl From<VxlanDecapError> for VxlanError {
+    fn from(value: VxlanDecapError) -> Self {
+        Self::Decap(value)
+    }
+}
+
+impl From<VxlanEncapError> for VxlanError {
+    fn from(value: VxlanEncapError) -> Self {
+        Self::Encap(value)
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for VxlanError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum VxlanBpfCreationError {
+    InvalidFlags { flags: u8 },
+    HeaderTooShort { size: usize, expected: usize },
+    BpfOutOfBounds,
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for VxlanBpfCreationError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+impl From<CursorError> for VxlanBpfCreationError {
+    fn from(value: CursorError) -> Self {
+        match value {
+            CursorError::TooShort { size, expected } => Self::HeaderTooShort { size, expected },
+            CursorError::BpfOutOfBounds => Self::BpfOutOfBounds,
+            CursorError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            CursorError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for VxlanBpfCreationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidFlags { flags } => {
+                write!(
+                    f,
+                    "VXLAN I flag expected to be set, flags were: {flags:#04X}"
+                )
+            }
+            Self::HeaderTooShort { size, expected } => {
+                write!(
+                    f,
+                    "VXLAN header expected to be at least {expected} bytes, was: {size}"
+                )
+            }
+            Self::BpfOutOfBounds => {
+                write!(f, "Bpf out of bounds")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for VxlanBpfCreationError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum VxlanGetHeaderError {
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for VxlanGetHeaderError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for VxlanGetHeaderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for VxlanGetHeaderError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum VxlanDecapError {
+    WrongPort { port: u16 },
+    Udp(UdpBpfCreationError),
+    Vxlan(VxlanBpfCreationError),
+    InnerFrame(EthernetBpfAutoVlanCreationError),
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<UdpBpfCreationError> for VxlanDecapError {
+    fn from(value: UdpBpfCreationError) -> Self {
+        Self::Udp(value)
+    }
+}
+
+impl From<UdpGetHeaderError> for VxlanDecapError {
+    fn from(value: UdpGetHeaderError) -> Self {
+        match value {
+            UdpGetHeaderError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            UdpGetHeaderError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+impl From<VxlanBpfCreationError> for VxlanDecapError {
+    fn from(value: VxlanBpfCreationError) -> Self {
+        Self::Vxlan(value)
+    }
+}
+
+impl From<EthernetBpfAutoVlanCreationError> for VxlanDecapError {
+    fn from(value: EthernetBpfAutoVlanCreationError) -> Self {
+        Self::InnerFrame(value)
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for VxlanDecapError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::WrongPort { port } => {
+                write!(f, "UDP destination port expected to be 4789, was: {port}")
+            }
+            Self::Udp(err) => {
+                write!(f, "{err}")
+            }
+            Self::Vxlan(err) => {
+                write!(f, "{err}")
+            }
+            Self::InnerFrame(err) => {
+                write!(f, "Inner frame: {err}")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for VxlanDecapError {}
+
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum VxlanEncapError {
+    PacketTooShort { size: usize, expected: usize },
+    PacketTooLong { size: usize },
+    OuterFrame(EthernetBpfAutoVlanCreationError),
+    BpfOutOfBounds,
+    OutOfBoundsBufferAccess,
+    CouldNotConvertSliceToArray,
+}
+
+impl From<TryFromSliceError> for VxlanEncapError {
+    fn from(_: TryFromSliceError) -> Self {
+        Self::CouldNotConvertSliceToArray
+    }
+}
+
+impl From<CursorError> for VxlanEncapError {
+    fn from(value: CursorError) -> Self {
+        match value {
+            CursorError::TooShort { size, expected } => Self::PacketTooShort { size, expected },
+            CursorError::BpfOutOfBounds => Self::BpfOutOfBounds,
+            CursorError::OutOfBoundsBufferAccess => Self::OutOfBoundsBufferAccess,
+            CursorError::CouldNotConvertSliceToArray => Self::CouldNotConvertSliceToArray,
+        }
+    }
+}
+
+impl From<EthernetBpfAutoVlanCreationError> for VxlanEncapError {
+    fn from(value: EthernetBpfAutoVlanCreationError) -> Self {
+        Self::OuterFrame(value)
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Display for VxlanEncapError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::PacketTooShort { size, expected } => {
+                write!(
+                    f,
+                    "Frame with headroom expected to be at least {expected} bytes, was: {size}"
+                )
+            }
+            Self::PacketTooLong { size } => {
+                write!(f, "Outer IP packet too long, was: {size}")
+            }
+            Self::OuterFrame(err) => {
+                write!(f, "Outer frame: {err}")
+            }
+            Self::BpfOutOfBounds => {
+                write!(f, "Bpf out of bounds")
+            }
+            Self::OutOfBoundsBufferAccess => {
+                write!(f, "Out of bound access, .get(...) returned None")
+            }
+            Self::CouldNotConvertSliceToArray => {
+                write!(f, "Could not convert slice to array")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error_trait")]
+impl core::error::Error for VxlanEncapError {}
@@ -0,0 +1,172 @@
+//! Entry points from aya's program contexts into `packet-parser`.
+//!
+//! `packet-parser` forbids unsafe code, turning the packet pointers of a program context into
+//! a slice is the one unsafe conversion every eBPF program needs. It lives here, so programs
+//! that only read packets can stay free of unsafe code. Mutable access stays `unsafe`, see
+//! [`packet_mut`].
+#![deny(unsafe_code)]
+#![no_std]
+
+use aya_bpf::programs::{TcContext, XdpContext};
+use packet_parser::cursor::PacketCursor;
+use packet_parser::ethernet::{EtherFrame, EthernetBpfAutoVlanCreationError};
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for aya_bpf::programs::XdpContext {}
+    impl Sealed for aya_bpf::programs::TcContext {}
+}
+
+/// Program contexts with direct packet access.
+///
+/// The trait is sealed, [`packet`] relies on `data` and `data_end` being set by the kernel.
+pub trait PacketContext: sealed::Sealed {
+    fn data(&self) -> usize;
+    fn data_end(&self) -> usize;
+}
+
+impl PacketContext for XdpContext {
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn data(&self) -> usize {
+        XdpContext::data(self)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn data_end(&self) -> usize {
+        XdpContext::data_end(self)
+    }
+}
+
+impl PacketContext for TcContext {
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn data(&self) -> usize {
+        TcContext::data(self)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn data_end(&self) -> usize {
+        TcContext::data_end(self)
+    }
+}
+
+/// Returns the packet as slice together with `data_end`, which is the `end` parameter of all
+/// `new_bpf*` constructors. The slice is empty if `data_end` lies before `data`.
+#[cfg_attr(feature = "inline", inline(always))]
+pub fn packet<C: PacketContext>(ctx: &C) -> (&[u8], usize) {
+    let start = ctx.data();
+    let end = ctx.data_end();
+    if end < start {
+        return (&[], end);
+    }
+    // SAFETY: `PacketContext` is sealed and only implemented for contexts whose `data` and
+    // `data_end` are set by the kernel, which guarantees that [data, data_end) is the readable
+    // packet. `start <= end` was checked above. The slice borrows `ctx`, so it can't outlive
+    // the program invocation. The packet is only written through `packet_mut`, whose caller
+    // rules out a live shared slice.
+    #[allow(unsafe_code)]
+    let data = unsafe { core::slice::from_raw_parts(start as *const u8, end - start) };
+    (data, end)
+}
+
+/// Like [`packet`], but returns a mutable slice.
+///
+/// # Safety
+///
+/// No other slice over the packet may be alive while the returned one is. The exclusive borrow
+/// of `ctx` doesn't ensure this, aya allows safe code to create a second context for the same
+/// packet, e.g. `XdpContext::new(ctx.ctx)`.
+#[allow(unsafe_code)]
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn packet_mut<C: PacketContext>(ctx: &mut C) -> (&mut [u8], usize) {
+    let start = ctx.data();
+    let end = ctx.data_end();
+    if end < start {
+        return (&mut [], end);
+    }
+    // SAFETY: Bounds as in `packet`, the caller guarantees that the slice is the only one over
+    // the packet.
+    let data = unsafe { core::slice::from_raw_parts_mut(start as *mut u8, end - start) };
+    (data, end)
+}
+
+/// Returns a [`PacketCursor`] over the packet, see [`packet`].
+#[cfg_attr(feature = "inline", inline(always))]
+pub fn packet_cursor<C: PacketContext>(ctx: &C) -> PacketCursor<&[u8]> {
+    let (data, end) = packet(ctx);
+    PacketCursor::new(data, end)
+}
+
+/// Returns a mutable [`PacketCursor`] over the packet.
+///
+/// # Safety
+///
+/// See [`packet_mut`].
+#[allow(unsafe_code)]
+#[cfg_attr(feature = "inline", inline(always))]
+pub unsafe fn packet_cursor_mut<C: PacketContext>(ctx: &mut C) -> PacketCursor<&mut [u8]> {
+    // SAFETY: The caller upholds the contract of `packet_mut`.
+    let (data, end) = unsafe { packet_mut(ctx) };
+    PacketCursor::new(data, end)
+}
+
+/// Read-only [`EtherFrame`] constructors for program contexts, VLAN tags are detected with
+/// [`EtherFrame::new_bpf_auto_vlan`].
+pub trait EtherFrameContextExt<'a>: Sized {
+    fn from_xdp(ctx: &'a XdpContext) -> Result<(Self, &'a [u8]), EthernetBpfAutoVlanCreationError>;
+    fn from_tc(ctx: &'a TcContext) -> Result<(Self, &'a [u8]), EthernetBpfAutoVlanCreationError>;
+}
+
+impl<'a> EtherFrameContextExt<'a> for EtherFrame<&'a [u8]> {
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn from_xdp(ctx: &'a XdpContext) -> Result<(Self, &'a [u8]), EthernetBpfAutoVlanCreationError> {
+        let (data, end) = packet(ctx);
+        EtherFrame::new_bpf_auto_vlan(data, end)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    fn from_tc(ctx: &'a TcContext) -> Result<(Self, &'a [u8]), EthernetBpfAutoVlanCreationError> {
+        let (data, end) = packet(ctx);
+        EtherFrame::new_bpf_auto_vlan(data, end)
+    }
+}
+
+/// Mutable [`EtherFrame`] constructors for program contexts, VLAN tags are detected with
+/// [`EtherFrame::new_bpf_auto_vlan`].
+#[allow(unsafe_code)]
+pub trait EtherFrameContextMutExt<'a>: Sized {
+    /// # Safety
+    ///
+    /// See [`packet_mut`].
+    unsafe fn from_xdp_mut(
+        ctx: &'a mut XdpContext,
+    ) -> Result<(Self, &'a mut [u8]), EthernetBpfAutoVlanCreationError>;
+
+    /// # Safety
+    ///
+    /// See [`packet_mut`].
+    unsafe fn from_tc_mut(
+        ctx: &'a mut TcContext,
+    ) -> Result<(Self, &'a mut [u8]), EthernetBpfAutoVlanCreationError>;
+}
+
+#[allow(unsafe_code)]
+impl<'a> EtherFrameContextMutExt<'a> for EtherFrame<&'a mut [u8]> {
+    #[cfg_attr(feature = "inline", inline(always))]
+    unsafe fn from_xdp_mut(
+        ctx: &'a mut XdpContext,
+    ) -> Result<(Self, &'a mut [u8]), EthernetBpfAutoVlanCreationError> {
+        // SAFETY: The caller upholds the contract of `packet_mut`.
+        let (data, end) = unsafe { packet_mut(ctx) };
+        EtherFrame::new_bpf_auto_vlan(data, end)
+    }
+
+    #[cfg_attr(feature = "inline", inline(always))]
+    unsafe fn from_tc_mut(
+        ctx: &'a mut TcContext,
+    ) -> Result<(Self, &'a mut [u8]), EthernetBpfAutoVlanCreationError> {
+        // SAFETY: The caller upholds the contract of `packet_mut`.
+        let (data, end) = unsafe { packet_mut(ctx) };
+        EtherFrame::new_bpf_auto_vlan(data, end)
+    }
+}
@@ -4,7 +4,8 @@
 use aya_bpf::{bindings::xdp_action, macros::xdp, programs::XdpContext};
 use aya_log_ebpf::info;
 use packet_parser::error::Error;
-use packet_parser::ethernet::{EtherFrame, EtherType, EthernetBpfWithVlanCreationError};
+use packet_parser::ethernet::{EtherFrame, EtherType, EthernetBpfAutoVlanCreationError};
+use packet_parser_aya_bpf::packet;
 
 #[xdp(name = "packet_parser_aya")]
 pub fn packet_parser_aya(ctx: XdpContext) -> u32 {
@@ -15,23 +16,20 @@ pub fn packet_parser_aya(ctx: XdpContext) -> u32 {
 }
 
 fn try_packet_parser_aya(ctx: XdpContext) -> Result<u32, Error> {
-    let start = ctx.data();
-    let end = ctx.data_end();
-    let len = ctx.data_end() - ctx.data();
-
-    let data = unsafe { core::slice::from_raw_parts_mut(start as *mut u8, len) };
-    EtherFrame::new_bpf(data, ctx.data_end())?;
-
-    match EtherFrame::new_bpf_with_vlan(data, ctx.data_end()) {
+    let (data, end) = packet(&ctx);
+    match EtherFrame::new_bpf_auto_vlan_lenient(data, end) {
         Ok((ethernet_frame, _)) => {
             // The next two info! lines trigger "R1 type=map_value expected=ctx".
             // Commenting out one of them changes the behavior.
             let dst = ethernet_frame.get_destination()?;
-            info!(&ctx, "Dst: {:mac}", dst);
+            info!(&ctx, "Dst: {:mac}", dst.as_bytes());
             let ether_type = u16::from_be_bytes(*ethernet_frame.get_ether_type()?);
             info!(&ctx, "EtherType: {:X}", ether_type);
 
             match ethernet_frame.get_typed_ether_type() {
+                EtherType::Ieee8023Length => {
+                    info!(&ctx, "IEEE 802.3")
+                }
                 EtherType::IpV4 => {
                     // Comment to trigger "jump out of range from insn"
                     info!(&ctx, "IpV4")
@@ -86,25 +84,30 @@ fn try_packet_parser_aya(ctx: XdpContext) -> Result<u32, Error> {
                 EtherType::Hsr => {}
                 EtherType::EthernetConfigurationTestingProtocol => {}
                 EtherType::RTag => {}
-                EtherType::EtherTypeErrorVariant => {}
+                EtherType::EtherTypeErrorVariant => {
+                    info!(&ctx, "Unknown EtherType")
+                }
             }
         }
 
         Err(err) => match err {
-            EthernetBpfWithVlanCreationError::NoRecognizedEtherType { .. } => {
+            EthernetBpfAutoVlanCreationError::NoRecognizedEtherType { .. } => {
                 info!(&ctx, "NoRecognizedEtherType")
             }
 
-            EthernetBpfWithVlanCreationError::FrameTooShort { .. } => {
+            EthernetBpfAutoVlanCreationError::TooManyVlanTags => {
+                info!(&ctx, "TooManyVlanTags")
+            }
+            EthernetBpfAutoVlanCreationError::FrameTooShort { .. } => {
                 info!(&ctx, "FrameTooShort")
             }
-            EthernetBpfWithVlanCreationError::OutOfBoundsBufferAccess => {
+            EthernetBpfAutoVlanCreationError::OutOfBoundsBufferAccess => {
                 info!(&ctx, "OutOfBoundsBufferAccess")
             }
-            EthernetBpfWithVlanCreationError::CouldNotConvertSliceToArray => {
+            EthernetBpfAutoVlanCreationError::CouldNotConvertSliceToArray => {
                 info!(&ctx, "CouldNotConvertSliceToArray")
             }
-            EthernetBpfWithVlanCreationError::BpfOutOfBounds => {
+            EthernetBpfAutoVlanCreationError::BpfOutOfBounds => {
                 info!(&ctx, "BpfOutOfBounds")
             }
         },